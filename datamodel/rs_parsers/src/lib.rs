@@ -54,32 +54,50 @@ fn to_string(py: Python, obj: Option<Py<PyAny>>) -> PyResult<Option<String>> {
     }
 }
 
+/// Converts a Python list by calling `py_type` (any callable) on each item,
+/// splitting the work into chunks of at least `min_chunk` items dispatched
+/// via rayon instead of reacquiring the GIL once per item.
+///
+/// `py_type` is an arbitrary callable, so unlike
+/// `rst_converters::to_list_parallel`'s fixed `"int"`/`"float"`/`"bool"`/
+/// `"str"` dispatch, every call still needs the GIL — but reacquiring it
+/// once per chunk (via `Python::with_gil` inside a `rayon` worker) instead
+/// of once per item, with the GIL released between chunks via
+/// `py.allow_threads`, avoids a thread monopolizing it for the whole list.
 #[pyfunction]
-#[pyo3(signature = (py_type, input_list))]
-fn to_list(py: Python, py_type: Py<PyAny>, input_list: Py<PyList>) -> PyResult<PyObject> {
+#[pyo3(signature = (py_type, input_list, min_chunk=1024))]
+fn to_list(py: Python, py_type: Py<PyAny>, input_list: Py<PyList>, min_chunk: usize) -> PyResult<PyObject> {
     let input_list = input_list.bind(py);
 
     // Ensure py_type is callable
-    let py_type = py_type.bind(py);
-    if !py_type.is_callable() {
+    if !py_type.bind(py).is_callable() {
         return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>("Provided type is not callable"));
     }
 
-    let mut result_list: Vec<PyObject> = Vec::new();
-
-    for item in input_list.iter() {
-        let converted_item = Python::with_gil(|py| {
-            let py_type = py_type.clone();
-            let item_obj: PyObject = item.into();
-            py_type.call1((item_obj,)).map(|obj| obj.into())
-        });
-        result_list.push(converted_item?);
+    let items: Vec<PyObject> = input_list.iter().map(|item| item.into()).collect();
+    let chunk_size = min_chunk.max(1);
+
+    let converted: Vec<PyResult<PyObject>> = py.allow_threads(|| {
+        items
+            .par_chunks(chunk_size)
+            .flat_map(|chunk| {
+                Python::with_gil(|py| {
+                    let py_type = py_type.clone_ref(py);
+                    chunk
+                        .iter()
+                        .map(|item| py_type.call1(py, (item.clone_ref(py),)))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect()
+    });
+
+    let mut result_list = Vec::with_capacity(converted.len());
+    for item in converted {
+        result_list.push(item?);
     }
 
-    Python::with_gil(|py| {
-        let py_list = PyList::new(py, &result_list)?;
-        Ok(py_list.into())
-    })
+    Ok(PyList::new(py, &result_list)?.into())
 }
 
 #[pyfunction]
@@ -1,16 +1,19 @@
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
-use pyo3::exceptions::PyTypeError;
 use pyo3::wrap_pyfunction;
 use pyo3::types::PyType;
-use pyo3::types::{PyDate, PyDateTime, PyAny, PyDict, PyTime};
+use pyo3::types::{PyDate, PyDateAccess, PyDateTime, PyAny, PyDict, PyList, PyTime, PyTimeAccess, PyDelta, PyDeltaAccess, PyTzInfo};
 // use pyo3::PyTypeInfo;
 use chrono::{Datelike, Timelike, NaiveDate, NaiveTime, NaiveDateTime, DateTime, Utc};
 use speedate::Date as SpeeDate;
 use speedate::DateTime as SpeeDateTime;
+use speedate::Time as SpeeTime;
 // use speedate::{Date, DateTime, ParseError};
 use rayon::prelude::*;
-// use std::collections::HashMap;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use std::collections::HashMap;
+use std::str::FromStr;
 
 
 /// Converts a string representation of truth to a boolean.
@@ -93,20 +96,392 @@ fn to_timestamp(py: Python, timestamp: f64) -> PyResult<PyObject> {
     }
 }
 
+/// A thread-safe scalar extracted from a Python object up front, so
+/// `to_list_parallel` can hand the actual coercion work to rayon without
+/// touching the GIL.
+#[derive(Debug, Clone)]
+enum ScalarInput {
+    Str(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    None,
+}
+
+/// The result of coercing a `ScalarInput` to `py_type`, turned back into a
+/// Python object once the GIL is reacquired.
+#[derive(Debug, Clone)]
+enum ScalarOutput {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    None,
+}
+
+impl ScalarOutput {
+    fn into_py(self, py: Python) -> PyObject {
+        match self {
+            ScalarOutput::Int(i) => i.into_py(py),
+            ScalarOutput::Float(f) => f.into_py(py),
+            ScalarOutput::Bool(b) => b.into_py(py),
+            ScalarOutput::Str(s) => s.into_py(py),
+            ScalarOutput::None => py.None(),
+        }
+    }
+}
+
+/// Pure-Rust coercion of one `ScalarInput` to `py_type`, with no GIL access
+/// so it can run inside `par_chunks`.
+fn coerce_scalar(value: &ScalarInput, py_type: &str) -> PyResult<ScalarOutput> {
+    if matches!(value, ScalarInput::None) {
+        return Ok(ScalarOutput::None);
+    }
+    match py_type {
+        "int" => match value {
+            ScalarInput::Str(s) => s.trim().parse::<i64>().map(ScalarOutput::Int).map_err(|_| {
+                PyValueError::new_err(format!("Cannot convert '{}' to int", s))
+            }),
+            ScalarInput::Int(i) => Ok(ScalarOutput::Int(*i)),
+            ScalarInput::Float(f) => Ok(ScalarOutput::Int(*f as i64)),
+            ScalarInput::Bool(b) => Ok(ScalarOutput::Int(*b as i64)),
+            ScalarInput::None => unreachable!(),
+        },
+        "float" => match value {
+            ScalarInput::Str(s) => s.trim().parse::<f64>().map(ScalarOutput::Float).map_err(|_| {
+                PyValueError::new_err(format!("Cannot convert '{}' to float", s))
+            }),
+            ScalarInput::Int(i) => Ok(ScalarOutput::Float(*i as f64)),
+            ScalarInput::Float(f) => Ok(ScalarOutput::Float(*f)),
+            ScalarInput::Bool(b) => Ok(ScalarOutput::Float(if *b { 1.0 } else { 0.0 })),
+            ScalarInput::None => unreachable!(),
+        },
+        "bool" => match value {
+            ScalarInput::Str(s) => strtobool(s).map(ScalarOutput::Bool),
+            ScalarInput::Int(i) => Ok(ScalarOutput::Bool(*i != 0)),
+            ScalarInput::Float(f) => Ok(ScalarOutput::Bool(*f != 0.0)),
+            ScalarInput::Bool(b) => Ok(ScalarOutput::Bool(*b)),
+            ScalarInput::None => unreachable!(),
+        },
+        "str" => match value {
+            ScalarInput::Str(s) => Ok(ScalarOutput::Str(s.clone())),
+            ScalarInput::Int(i) => Ok(ScalarOutput::Str(i.to_string())),
+            ScalarInput::Float(f) => Ok(ScalarOutput::Str(f.to_string())),
+            ScalarInput::Bool(b) => Ok(ScalarOutput::Str(b.to_string())),
+            ScalarInput::None => unreachable!(),
+        },
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported py_type '{}'. Expected one of 'int', 'float', 'bool', 'str'",
+            other
+        ))),
+    }
+}
+
+/// Serial, GIL-holding fallback for `to_list_parallel`: coerces every item
+/// via the matching Python builtin (`int`/`float`/`bool`/`str`), used when
+/// the input contains anything besides the plain scalars `coerce_scalar`
+/// understands (e.g. a callable or an arbitrary object).
+fn to_list_serial(py: Python, py_type: &str, input_list: &PyList) -> PyResult<PyObject> {
+    let ctor = py.import("builtins")?.getattr(py_type).map_err(|_| {
+        PyValueError::new_err(format!(
+            "Unsupported py_type '{}'. Expected one of 'int', 'float', 'bool', 'str'",
+            py_type
+        ))
+    })?;
+    let mut results = Vec::with_capacity(input_list.len());
+    for item in input_list.iter() {
+        results.push(if item.is_none() {
+            py.None()
+        } else {
+            ctor.call1((item,))?.into_py(py)
+        });
+    }
+    Ok(PyList::new(py, results).into_py(py))
+}
+
+/// Converts a Python list to a list of `py_type`-coerced values
+/// (`"int"`/`"float"`/`"bool"`/`"str"`), splitting the work into chunks of
+/// at least `min_chunk` items and running the coercion off the GIL via
+/// rayon.
+///
+/// Each element is first extracted into a thread-safe `ScalarInput`
+/// (`str`/`int`/`float`/`bool`/`None`) while the GIL is held; if every
+/// element extracts cleanly, the actual parsing runs across
+/// `par_chunks(min_chunk)` with the GIL released. If any element doesn't
+/// extract into one of those plain scalars (an arbitrary object, a
+/// callable, ...), the whole call falls back to [`to_list_serial`], which
+/// is still correct but forgoes the rayon speedup.
+///
+/// # Arguments
+/// * `py_type` - One of `"int"`, `"float"`, `"bool"`, `"str"`.
+/// * `input_list` - The list of values to convert.
+/// * `min_chunk` - The minimum chunk size handed to each rayon task.
+#[pyfunction]
+#[pyo3(signature = (py_type, input_list, min_chunk=1024))]
+fn to_list_parallel(py: Python, py_type: &str, input_list: &PyList, min_chunk: usize) -> PyResult<PyObject> {
+    let mut extracted: Vec<ScalarInput> = Vec::with_capacity(input_list.len());
+    for item in input_list.iter() {
+        let scalar = if item.is_none() {
+            ScalarInput::None
+        } else if let Ok(b) = item.extract::<bool>() {
+            ScalarInput::Bool(b)
+        } else if let Ok(i) = item.extract::<i64>() {
+            ScalarInput::Int(i)
+        } else if let Ok(f) = item.extract::<f64>() {
+            ScalarInput::Float(f)
+        } else if let Ok(s) = item.extract::<String>() {
+            ScalarInput::Str(s)
+        } else {
+            return to_list_serial(py, py_type, input_list);
+        };
+        extracted.push(scalar);
+    }
+
+    let chunk_size = min_chunk.max(1);
+    let coerced: Vec<PyResult<ScalarOutput>> = py.allow_threads(|| {
+        extracted
+            .par_chunks(chunk_size)
+            .flat_map(|chunk| chunk.iter().map(|v| coerce_scalar(v, py_type)).collect::<Vec<_>>())
+            .collect()
+    });
+
+    let mut results = Vec::with_capacity(coerced.len());
+    for value in coerced {
+        results.push(value?.into_py(py));
+    }
+    Ok(PyList::new(py, results).into_py(py))
+}
+
+/// PostgreSQL-style `DateOrder`, used to disambiguate numeric dates like
+/// `03/04/2023` where either reading is otherwise plausible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateOrder {
+    Mdy,
+    Dmy,
+    Ymd,
+}
+
+impl DateOrder {
+    fn from_str(value: &str) -> PyResult<Self> {
+        match value.to_uppercase().as_str() {
+            "MDY" => Ok(DateOrder::Mdy),
+            "DMY" => Ok(DateOrder::Dmy),
+            "YMD" => Ok(DateOrder::Ymd),
+            other => Err(PyValueError::new_err(format!(
+                "Invalid date_order '{}'. Expected one of 'MDY', 'DMY', 'YMD'",
+                other
+            ))),
+        }
+    }
+
+    /// Ambiguous `%.../%.../%...`-style formats in the order this `DateOrder`
+    /// prefers them to be tried, so the user's intended reading wins.
+    fn ordered_formats(self) -> Vec<&'static str> {
+        match self {
+            DateOrder::Mdy => vec!["%m/%d/%Y", "%m-%d-%Y", "%d/%m/%Y", "%d-%m-%Y", "%d.%m.%Y"],
+            DateOrder::Dmy => vec!["%d/%m/%Y", "%d-%m-%Y", "%d.%m.%Y", "%m/%d/%Y", "%m-%d-%Y"],
+            DateOrder::Ymd => vec!["%Y/%m/%d", "%Y-%m-%d", "%m/%d/%Y", "%d/%m/%Y", "%m-%d-%Y", "%d-%m-%Y", "%d.%m.%Y"],
+        }
+    }
+}
+
+/// Attempts to resolve a bare numeric triple (`03/04/2023`, `2023-04-03`, ...)
+/// directly, instead of relying on whichever `chrono` format happens to
+/// match first. Returns `None` if the input isn't a clean `n/n/n` triple,
+/// if neither the first nor the last component can be taken as the year
+/// (an all-small triple such as `3-4-23` is not resolved), or if the
+/// resulting month/day are out of range.
+fn disambiguate_numeric_date(input: &str, date_order: DateOrder) -> Option<(i32, u32, u32)> {
+    let separators = ['/', '-', '.'];
+    let sep = separators.iter().find(|&&s| input.contains(s))?;
+    let parts: Vec<&str> = input.split(*sep).collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    let numbers: Vec<i32> = parts.iter().map(|p| p.parse::<i32>()).collect::<Result<_, _>>().ok()?;
+    let [a, b, c] = numbers[..] else { return None };
+
+    // Figure out which slot is the year: the first slot if it has four
+    // digits or exceeds 31, else the last slot by the same test; if
+    // neither qualifies, give up.
+    let (year, month, day) = if a > 31 || parts[0].len() == 4 {
+        // Y/M/D
+        match date_order {
+            DateOrder::Dmy if b > 12 => (a, c, b),
+            _ => (a, b, c),
+        }
+    } else if c > 31 || parts[2].len() == 4 {
+        // first two components are month/day in some order, year is last
+        match date_order {
+            DateOrder::Dmy => (c, b, a),
+            _ => (c, a, b),
+        }
+    } else {
+        return None;
+    };
+
+    if (1..=12).contains(&month) && (1..=31).contains(&day) {
+        Some((year, month as u32, day as u32))
+    } else {
+        None
+    }
+}
+
+/// Case-insensitive lookup of a month name or 3-letter abbreviation
+/// (`"Jun"`, `"june"`, ...), for `fuzzy_extract_date`.
+fn month_from_name(token: &str) -> Option<u32> {
+    match token.to_lowercase().as_str() {
+        "jan" | "january" => Some(1),
+        "feb" | "february" => Some(2),
+        "mar" | "march" => Some(3),
+        "apr" | "april" => Some(4),
+        "may" => Some(5),
+        "jun" | "june" => Some(6),
+        "jul" | "july" => Some(7),
+        "aug" | "august" => Some(8),
+        "sep" | "sept" | "september" => Some(9),
+        "oct" | "october" => Some(10),
+        "nov" | "november" => Some(11),
+        "dec" | "december" => Some(12),
+        _ => None,
+    }
+}
+
+/// Strips a trailing ordinal suffix (`1st`, `2nd`, `3rd`, `17th`) off a
+/// purely-numeric token, for `fuzzy_extract_date`.
+fn strip_ordinal_suffix(token: &str) -> &str {
+    for suffix in ["st", "nd", "rd", "th"] {
+        if let Some(digits) = token.strip_suffix(suffix) {
+            if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                return digits;
+            }
+        }
+    }
+    token
+}
+
+/// Extracts a date out of free text like `"I first released this on the
+/// 17th of June, 2011"`, following the dtparse/dateutil approach: split the
+/// input into alphanumeric runs, classify each token (4-digit number =
+/// year, month name/abbreviation = month, remaining numbers from 1 to 31
+/// fill day and whatever of month/year is still missing; any other number,
+/// such as a 2-digit `99`, is ignored), and default any
+/// component that's still unset from today's date. `dayfirst` resolves the
+/// remaining-number ambiguity when no month name was found: `false` (the
+/// default) assigns the first remaining number to the month, `true` to the
+/// day. Returns `None` only when no usable date token was found at all, or
+/// the assembled components don't form a real calendar date.
+fn fuzzy_extract_date(py: Python, input: &str, dayfirst: bool) -> Option<(i32, u32, u32)> {
+    let mut year: Option<i32> = None;
+    let mut month: Option<u32> = None;
+    let mut numbers: Vec<u32> = Vec::new();
+    let mut found_any = false;
+
+    for raw_token in input.split(|c: char| !c.is_ascii_alphanumeric()) {
+        if raw_token.is_empty() {
+            continue;
+        }
+        let token = strip_ordinal_suffix(raw_token);
+        if token.chars().all(|c| c.is_ascii_digit()) {
+            if let Ok(n) = token.parse::<i64>() {
+                if token.len() == 4 {
+                    year = Some(n as i32);
+                    found_any = true;
+                } else if (1..=31).contains(&n) {
+                    numbers.push(n as u32);
+                    found_any = true;
+                }
+            }
+        } else if let Some(m) = month_from_name(token) {
+            month = Some(m);
+            found_any = true;
+        }
+    }
+
+    if !found_any {
+        return None;
+    }
+
+    let mut numbers = numbers.into_iter();
+    let day = if month.is_some() {
+        numbers.next()
+    } else if dayfirst {
+        let day = numbers.next();
+        month = numbers.next();
+        day
+    } else {
+        month = numbers.next();
+        numbers.next()
+    };
+    if year.is_none() {
+        year = numbers.next().map(|n| if n < 100 { 2000 + n as i32 } else { n as i32 });
+    }
+
+    let today = py.import("datetime").ok()?.getattr("date").ok()?.call_method0("today").ok()?;
+    let year = year.unwrap_or(today.getattr("year").ok()?.extract::<i32>().ok()?);
+    let month = month.unwrap_or(today.getattr("month").ok()?.extract::<u32>().ok()?);
+    let day = day.unwrap_or(today.getattr("day").ok()?.extract::<u32>().ok()?);
+
+    NaiveDate::from_ymd_opt(year, month, day)?;
+    Some((year, month, day))
+}
+
 /// Parses a string into a `NaiveDate` using multiple formats.
 ///
 /// # Arguments
 /// * `input` - The string to parse.
+/// * `custom_format` - An optional extra `chrono` format to try.
+/// * `date_order` - How to resolve an ambiguous numeric date (`"MDY"`,
+///   `"DMY"`, or `"YMD"`, the default, which keeps the previous ISO-first
+///   behavior).
+/// * `fuzzy` - If `true`, extract a date out of free text instead of
+///   requiring the whole string to match a format; see
+///   `fuzzy_extract_date`.
+/// * `dayfirst` - In fuzzy mode, whether an ambiguous leading number is the
+///   day (`true`) or the month (`false`, the default).
+/// * `strict` - If `true`, require the exact ISO-8601/XSD lexical form
+///   (4-digit year, zero-padded fields) via speedate, with precise field-range
+///   validation (month 1-12, day within the actual month length including
+///   leap years) and no fallback to the ambiguous `%m/%d/%Y`/`%d/%m/%Y`
+///   format list. Mutually exclusive with `fuzzy`; `fuzzy` wins if both are set.
 ///
 /// # Returns
 /// * `Ok(NaiveDate)` if parsing succeeds.
-/// * `Err(PyValueError)` if no format matches.
+/// * `Err(PyValueError)` if no format matches. In strict mode, the error
+///   names the offending component.
 #[pyfunction]
-fn to_date(py: Python, input: &str, custom_format: Option<&str>) -> PyResult<Py<PyDate>> {
+#[pyo3(signature = (input, custom_format=None, date_order=None, fuzzy=false, dayfirst=false, strict=false))]
+fn to_date(py: Python, input: &str, custom_format: Option<&str>, date_order: Option<&str>, fuzzy: bool, dayfirst: bool, strict: bool) -> PyResult<Py<PyDate>> {
     if input.trim().is_empty() {
         return Err(PyValueError::new_err("Input string is empty"));
     }
 
+    if fuzzy {
+        return match fuzzy_extract_date(py, input, dayfirst) {
+            Some((year, month, day)) => Ok(PyDate::new(py, year, month as u8, day as u8)?.into_py(py)),
+            None => Err(PyValueError::new_err(format!(
+                "Unable to extract a date from '{}'",
+                input
+            ))),
+        };
+    }
+
+    if strict {
+        return match SpeeDate::parse_str(input) {
+            Ok(parsed) => Ok(PyDate::new(py, parsed.year as i32, parsed.month, parsed.day)?.into_py(py)),
+            Err(e) => Err(PyValueError::new_err(format!(
+                "Strict ISO-8601/XSD date validation failed for '{}': {:?}",
+                input, e
+            ))),
+        };
+    }
+
+    let date_order = match date_order {
+        Some(value) => DateOrder::from_str(value)?,
+        None => DateOrder::Ymd,
+    };
+
     // Use speedate for ISO 8601 parsing
     if let Ok(parsed_date) = SpeeDate::parse_str(input) {
         return Ok(PyDate::new(
@@ -118,19 +493,16 @@ fn to_date(py: Python, input: &str, custom_format: Option<&str>) -> PyResult<Py<
         .into_py(py));
     }
 
-    // Define custom formats to try, including the optional format.
-    let formats = vec![
-        "%Y-%m-%d",             // ISO 8601 date
-        "%m/%d/%Y",             // Month/day/year
-        "%m-%d-%Y",             // Month-day-year
-        "%d-%m-%Y",             // Custom format
-        "%Y/%m/%d",             // Slash-separated date
-        "%Y-%m-%dT%H:%M:%S%.f", // ISO 8601 datetime
-        "%Y-%m-%d %H:%M:%S",    // ISO 8601 with time
-        "%d/%m/%Y",             // Day/month/year
-        "%d.%m.%Y",             // Day.month.year
-        custom_format.unwrap_or_default(),
-    ];
+    // Resolve a bare numeric triple directly, honoring `date_order`.
+    if let Some((year, month, day)) = disambiguate_numeric_date(input.trim(), date_order) {
+        return Ok(PyDate::new(py, year, month as u8, day as u8)?.into_py(py));
+    }
+
+    // Define the candidate formats, with the ambiguous ones reordered to
+    // respect `date_order`, plus the optional custom format.
+    let mut formats = vec!["%Y-%m-%d", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S"];
+    formats.extend(date_order.ordered_formats());
+    formats.push(custom_format.unwrap_or_default());
 
     for &fmt in &formats {
         if let Ok(date) = NaiveDate::parse_from_str(input, fmt) {
@@ -144,15 +516,94 @@ fn to_date(py: Python, input: &str, custom_format: Option<&str>) -> PyResult<Py<
     )))
 }
 
+/// Build a fixed-offset `datetime.timezone` instance from an offset in seconds.
+fn fixed_offset_tzinfo(py: Python, offset_seconds: i32) -> PyResult<Py<PyTzInfo>> {
+    let delta = PyDelta::new(py, 0, offset_seconds, 0, true)?;
+    let timezone_cls = py.import("datetime")?.getattr("timezone")?;
+    let tz = timezone_cls.call1((delta,))?;
+    Ok(tz.downcast::<PyTzInfo>()?.into())
+}
+
+/// Build the UTC `datetime.timezone.utc` singleton, for `assume_utc`.
+fn utc_tzinfo(py: Python) -> PyResult<Py<PyTzInfo>> {
+    let tz = py.import("datetime")?.getattr("timezone")?.getattr("utc")?;
+    Ok(tz.downcast::<PyTzInfo>()?.into())
+}
+
+/// Resolve a named zone (e.g. `"America/Caracas"`) via Python's
+/// `zoneinfo.ZoneInfo`, for `assume_tz`.
+fn named_tzinfo(py: Python, name: &str) -> PyResult<Py<PyTzInfo>> {
+    let zoneinfo_cls = py.import("zoneinfo")?.getattr("ZoneInfo")?;
+    let tz = zoneinfo_cls.call1((name,))?;
+    Ok(tz.downcast::<PyTzInfo>()?.into())
+}
+
+/// Resolve the `tzinfo` to localize a naive (offset-less) parse result with,
+/// preferring `assume_tz` (a named zone) over `assume_utc`.
+fn naive_tzinfo(py: Python, assume_utc: bool, assume_tz: Option<&str>) -> PyResult<Option<Py<PyTzInfo>>> {
+    match assume_tz {
+        Some(name) => Ok(Some(named_tzinfo(py, name)?)),
+        None if assume_utc => Ok(Some(utc_tzinfo(py)?)),
+        None => Ok(None),
+    }
+}
+
+/// Parses a string into a `PyDateTime`, trying Speedate's ISO 8601 parser
+/// first and falling back to RFC 3339/2822 and a list of `NaiveDateTime`
+/// formats (respecting `date_order`).
+///
+/// `strict`, if `true`, requires the exact ISO-8601/XSD lexical form
+/// (4-digit year, zero-padded fields) via speedate, with sub-second
+/// precision preserved as microseconds without rounding drift, and skips
+/// the lenient fallback chain entirely. A `Z`/`±HH:MM` offset is kept when
+/// present; an input without one is accepted and localized like any naive
+/// input (`assume_tz`/`assume_utc`). On failure the error names the
+/// offending component.
 #[pyfunction]
-fn to_datetime(py: Python, input: &str, custom_format: Option<&str>) -> PyResult<Py<PyDateTime>> {
+#[pyo3(signature = (input, custom_format=None, assume_utc=false, date_order=None, assume_tz=None, strict=false))]
+fn to_datetime(py: Python, input: &str, custom_format: Option<&str>, assume_utc: bool, date_order: Option<&str>, assume_tz: Option<&str>, strict: bool) -> PyResult<Py<PyDateTime>> {
+    let date_order = match date_order {
+        Some(value) => DateOrder::from_str(value)?,
+        None => DateOrder::Ymd,
+    };
 
     if input.trim().is_empty() {
         return Err(PyValueError::new_err("Input string is empty"));
     }
 
-    // Attempt parsing using Speedate
+    if strict {
+        return match SpeeDateTime::parse_str(input) {
+            Ok(parsed_datetime) => {
+                let tzinfo = match parsed_datetime.time.tz_offset {
+                    Some(offset) => Some(fixed_offset_tzinfo(py, offset)?),
+                    None => naive_tzinfo(py, assume_utc, assume_tz)?,
+                };
+                Ok(PyDateTime::new(
+                    py,
+                    parsed_datetime.date.year as i32,
+                    parsed_datetime.date.month,
+                    parsed_datetime.date.day,
+                    parsed_datetime.time.hour,
+                    parsed_datetime.time.minute,
+                    parsed_datetime.time.second,
+                    parsed_datetime.time.microsecond,
+                    tzinfo.as_ref().map(|tz| tz.as_ref(py)),
+                )?
+                .into_py(py))
+            }
+            Err(e) => Err(PyValueError::new_err(format!(
+                "Strict ISO-8601/XSD datetime validation failed for '{}': {:?}",
+                input, e
+            ))),
+        };
+    }
+
+    // Attempt parsing using Speedate, preserving any UTC offset it captured.
     if let Ok(parsed_datetime) = SpeeDateTime::parse_str(input) {
+        let tzinfo = match parsed_datetime.time.tz_offset {
+            Some(offset) => Some(fixed_offset_tzinfo(py, offset)?),
+            None => naive_tzinfo(py, assume_utc, assume_tz)?,
+        };
         return Ok(PyDateTime::new(
             py,
             parsed_datetime.date.year as i32,
@@ -162,50 +613,84 @@ fn to_datetime(py: Python, input: &str, custom_format: Option<&str>) -> PyResult
             parsed_datetime.time.minute,
             parsed_datetime.time.second,
             parsed_datetime.time.microsecond,
-            None,
+            tzinfo.as_ref().map(|tz| tz.as_ref(py)),
         )?
         .into_py(py));
     }
 
-    // Try parsing as ISO 8601 datetime with timezone.
+    // Try parsing as ISO 8601 datetime with timezone, keeping the parsed offset
+    // instead of normalizing to UTC.
     if let Ok(datetime) = DateTime::parse_from_rfc3339(input) {
-        let datetime_utc = datetime.with_timezone(&Utc);
-        return Ok(PyDateTime::from_timestamp(py, datetime_utc.timestamp() as f64, None)?.into_py(py));
+        let offset = datetime.offset().local_minus_utc();
+        let tzinfo = fixed_offset_tzinfo(py, offset)?;
+        let naive = datetime.naive_local();
+        let microseconds = naive.and_utc().timestamp_subsec_micros();
+        return Ok(PyDateTime::new(
+            py,
+            naive.date().year(),
+            naive.date().month() as u8,
+            naive.date().day() as u8,
+            naive.time().hour() as u8,
+            naive.time().minute() as u8,
+            naive.time().second() as u8,
+            microseconds,
+            Some(tzinfo.as_ref(py)),
+        )?
+        .into_py(py));
+    }
+
+    // Try parsing as an RFC 2822 / email-style datetime, e.g.
+    // "Tue, 01 Jan 2023 12:00:00 +0500" or "Wed, 02 Oct 2002 13:00:00 GMT".
+    if let Ok(datetime) = DateTime::parse_from_rfc2822(input) {
+        let offset = datetime.offset().local_minus_utc();
+        let tzinfo = fixed_offset_tzinfo(py, offset)?;
+        let naive = datetime.naive_local();
+        let microseconds = naive.and_utc().timestamp_subsec_micros();
+        return Ok(PyDateTime::new(
+            py,
+            naive.date().year(),
+            naive.date().month() as u8,
+            naive.date().day() as u8,
+            naive.time().hour() as u8,
+            naive.time().minute() as u8,
+            naive.time().second() as u8,
+            microseconds,
+            Some(tzinfo.as_ref(py)),
+        )?
+        .into_py(py));
     }
 
     // Try parsing as ISO 8601 datetime without fractional seconds.
     if let Ok(datetime) = NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M:%S") {
+        let tzinfo = naive_tzinfo(py, assume_utc, assume_tz)?;
         return Ok(PyDateTime::new(py, datetime.date().year(), datetime.date().month() as u8, datetime.date().day() as u8,
-            datetime.time().hour() as u8, datetime.time().minute() as u8, datetime.time().second() as u8, 0, None)?.into_py(py));
+            datetime.time().hour() as u8, datetime.time().minute() as u8, datetime.time().second() as u8, 0,
+            tzinfo.as_ref().map(|tz| tz.as_ref(py)))?.into_py(py));
     }
 
     // Try parsing as ISO 8601 datetime with fractional seconds.
     if let Ok(datetime) = NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M:%S%.f") {
         let microseconds = datetime.and_utc().timestamp_micros() as u32 % 1_000_000;
+        let tzinfo = naive_tzinfo(py, assume_utc, assume_tz)?;
         return Ok(PyDateTime::new(py, datetime.date().year(), datetime.date().month() as u8, datetime.date().day() as u8,
-            datetime.time().hour() as u8, datetime.time().minute() as u8, datetime.time().second() as u8, microseconds, None)?.into_py(py));
+            datetime.time().hour() as u8, datetime.time().minute() as u8, datetime.time().second() as u8, microseconds,
+            tzinfo.as_ref().map(|tz| tz.as_ref(py)))?.into_py(py));
     }
 
-    // Define custom formats to try, including the optional format.
-    let formats = vec![
-        "%Y-%m-%d",             // ISO 8601 date
-        "%m/%d/%Y",             // Month/day/year
-        "%m-%d-%Y",             // Month-day-year
-        "%d-%m-%Y",             // Custom format
-        "%Y/%m/%d",             // Slash-separated date
-        "%Y-%m-%dT%H:%M:%S%.f", // ISO 8601 datetime
-        "%Y-%m-%d %H:%M:%S",    // ISO 8601 with time
-        "%d/%m/%Y",             // Day/month/year
-        "%d.%m.%Y",             // Day.month.year
-        custom_format.unwrap_or_default(),
-    ];
+    // Define the candidate formats, with the ambiguous ones reordered to
+    // respect `date_order`, plus the optional custom format.
+    let mut formats = vec!["%Y-%m-%d", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S"];
+    formats.extend(date_order.ordered_formats());
+    formats.push(custom_format.unwrap_or_default());
 
     // Attempt parsing with each format.
     for &fmt in &formats {
         if let Ok(datetime) = NaiveDateTime::parse_from_str(input, fmt) {
             let microseconds = datetime.and_utc().timestamp_micros() as u32 % 1_000_000;
+            let tzinfo = naive_tzinfo(py, assume_utc, assume_tz)?;
             return Ok(PyDateTime::new(py, datetime.date().year(), datetime.date().month() as u8, datetime.date().day() as u8,
-                datetime.time().hour() as u8, datetime.time().minute() as u8, datetime.time().second() as u8, microseconds, None)?.into_py(py));
+                datetime.time().hour() as u8, datetime.time().minute() as u8, datetime.time().second() as u8, microseconds,
+                tzinfo.as_ref().map(|tz| tz.as_ref(py)))?.into_py(py));
         }
     }
 
@@ -216,78 +701,561 @@ fn to_datetime(py: Python, input: &str, custom_format: Option<&str>) -> PyResult
     )))
 }
 
+/// Parses a string into a `PyTime`, accepting `HH:MM`, `HH:MM:SS`,
+/// `HH:MM:SS.ffffff`, `HH:MM AM/PM`, and anything speedate's ISO time
+/// parser understands (including `Z`/`±HH:MM` offsets).
+///
+/// # Arguments
+/// * `input` - The string to parse.
+/// * `custom_format` - An optional extra `chrono` format to try.
+///
+/// # Returns
+/// * `Ok(PyTime)` if parsing succeeds. When an offset was parsed, the
+///   result carries a fixed-offset `tzinfo`; otherwise it's naive.
+/// * `Err(PyValueError)` if no format matches.
+#[pyfunction]
+#[pyo3(signature = (input, custom_format=None))]
+fn to_time(py: Python, input: &str, custom_format: Option<&str>) -> PyResult<Py<PyTime>> {
+    if input.trim().is_empty() {
+        return Err(PyValueError::new_err("Input string is empty"));
+    }
+
+    // Prefer speedate's ISO time parser (handles fractional seconds and offsets).
+    if let Ok(parsed_time) = SpeeTime::parse_str(input) {
+        let tzinfo = match parsed_time.tz_offset {
+            Some(offset) => Some(fixed_offset_tzinfo(py, offset)?),
+            None => None,
+        };
+        return Ok(PyTime::new(
+            py,
+            parsed_time.hour,
+            parsed_time.minute,
+            parsed_time.second,
+            parsed_time.microsecond,
+            tzinfo.as_ref().map(|tz| tz.as_ref(py)),
+        )?
+        .into_py(py));
+    }
+
+    let formats = vec![
+        "%H:%M:%S%.f", // HH:MM:SS.ffffff
+        "%H:%M:%S",    // HH:MM:SS
+        "%H:%M",       // HH:MM
+        "%I:%M %p",    // HH:MM AM/PM
+        custom_format.unwrap_or_default(),
+    ];
+
+    for &fmt in &formats {
+        if let Ok(time) = NaiveTime::parse_from_str(input, fmt) {
+            let microseconds = time.nanosecond() / 1_000;
+            return Ok(PyTime::new(
+                py,
+                time.hour() as u8,
+                time.minute() as u8,
+                time.second() as u8,
+                microseconds,
+                None,
+            )?
+            .into_py(py));
+        }
+    }
+
+    Err(PyValueError::new_err(format!(
+        "Unable to parse input '{}' into a time. Accepted formats: {:?}",
+        input, formats
+    )))
+}
+
+/// Pure-Rust (no GIL) check mirroring `to_date`'s default (non-strict,
+/// non-fuzzy, `YMD`) format chain, for callers like `FieldType::parse` that
+/// run off the GIL inside a `rayon` pool and only need a yes/no answer.
+fn date_str_is_parseable(input: &str) -> bool {
+    let input = input.trim();
+    if input.is_empty() {
+        return false;
+    }
+    if SpeeDate::parse_str(input).is_ok() {
+        return true;
+    }
+    if disambiguate_numeric_date(input, DateOrder::Ymd).is_some() {
+        return true;
+    }
+    let mut formats = vec!["%Y-%m-%d", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S"];
+    formats.extend(DateOrder::Ymd.ordered_formats());
+    formats.iter().any(|&fmt| NaiveDate::parse_from_str(input, fmt).is_ok())
+}
+
+/// Pure-Rust (no GIL) check mirroring `to_datetime`'s default (non-strict,
+/// `YMD`) format chain: speedate's ISO parser, RFC 3339, RFC 2822, then the
+/// `NaiveDateTime` format list. See `date_str_is_parseable` for why this
+/// can't just call `to_datetime` directly.
+fn datetime_str_is_parseable(input: &str) -> bool {
+    if input.trim().is_empty() {
+        return false;
+    }
+    if SpeeDateTime::parse_str(input).is_ok() {
+        return true;
+    }
+    if DateTime::parse_from_rfc3339(input).is_ok() {
+        return true;
+    }
+    if DateTime::parse_from_rfc2822(input).is_ok() {
+        return true;
+    }
+    if NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M:%S").is_ok() {
+        return true;
+    }
+    if NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M:%S%.f").is_ok() {
+        return true;
+    }
+    let mut formats = vec!["%Y-%m-%d", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S"];
+    formats.extend(DateOrder::Ymd.ordered_formats());
+    formats.iter().any(|&fmt| NaiveDateTime::parse_from_str(input, fmt).is_ok())
+}
+
+/// Pure-Rust (no GIL) check mirroring `to_time`'s format chain: speedate's
+/// ISO time parser, then `HH:MM:SS[.ffffff]`/`HH:MM`/`HH:MM AM/PM`.
+fn time_str_is_parseable(input: &str) -> bool {
+    if input.trim().is_empty() {
+        return false;
+    }
+    if SpeeTime::parse_str(input).is_ok() {
+        return true;
+    }
+    let formats = ["%H:%M:%S%.f", "%H:%M:%S", "%H:%M", "%I:%M %p"];
+    formats.iter().any(|&fmt| NaiveTime::parse_from_str(input, fmt).is_ok())
+}
+
+/// Converts an object to a `datetime.timedelta`.
+///
+/// Accepts, in order: an existing `timedelta` (passthrough), a numeric
+/// seconds count (`int`/`float`), an ISO 8601 duration string
+/// (`P[n]Y[n]M[n]DT[n]H[n]M[n]S`, plus the week form `PnW`), or a
+/// PostgreSQL-style interval phrase (`"1 day 2 hours 30 min"`).
+///
+/// Calendar components are approximated: a year is treated as 365 days and
+/// a month as 30 days, since `timedelta` has no notion of either.
+///
+/// # Arguments
+/// * `obj` - The value to convert.
+///
+/// # Returns
+/// * `Ok(timedelta)` if conversion succeeds.
+/// * `Err(PyValueError)` if `obj` is a string matching neither grammar, or
+///   is none of the accepted types.
 #[pyfunction]
-fn validate_datamodel(py: Python<'_>, dataclass_instance: PyObject) -> PyResult<Vec<(String, bool)>> {
+fn to_timedelta(py: Python, obj: &PyAny) -> PyResult<Py<PyDelta>> {
+    if let Ok(delta) = obj.downcast::<PyDelta>() {
+        return Ok(delta.into());
+    }
+
+    if let Ok(seconds) = obj.extract::<f64>() {
+        let days = (seconds / 86_400.0).floor() as i32;
+        let remainder = seconds - (days as f64 * 86_400.0);
+        let secs = remainder.floor() as i32;
+        let microseconds = (remainder.fract() * 1_000_000.0).round() as i32;
+        return Ok(PyDelta::new(py, days, secs, microseconds, true)?.into_py(py));
+    }
+
+    let input = obj.extract::<String>().map_err(|_| {
+        PyValueError::new_err("to_timedelta expects a timedelta, int, float, or string")
+    })?;
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(PyValueError::new_err("Input string is empty"));
+    }
+
+    if let Some(rest) = trimmed.strip_prefix('P') {
+        return parse_iso_duration(py, rest);
+    }
+
+    parse_interval_phrase(py, trimmed)
+}
+
+/// Parses the body of an ISO 8601 duration (everything after the leading `P`).
+/// Each component may carry its own leading `-` (e.g. `P-1DT1H` or
+/// `P1DT-1H`), since `parse_duration_number` forwards straight to
+/// `f64::parse`, which accepts it.
+fn parse_iso_duration(py: Python, rest: &str) -> PyResult<Py<PyDelta>> {
+    let (date_part, time_part) = match rest.split_once('T') {
+        Some((d, t)) => (d, Some(t)),
+        None => (rest, None),
+    };
+
+    let mut total_days: f64 = 0.0;
+    let mut total_seconds: f64 = 0.0;
+
+    let mut number = String::new();
+    for c in date_part.chars() {
+        match c {
+            '0'..='9' | '.' | '-' => number.push(c),
+            'Y' => {
+                total_days += parse_duration_number(&number)? * 365.0;
+                number.clear();
+            }
+            'M' => {
+                total_days += parse_duration_number(&number)? * 30.0;
+                number.clear();
+            }
+            'W' => {
+                total_days += parse_duration_number(&number)? * 7.0;
+                number.clear();
+            }
+            'D' => {
+                total_days += parse_duration_number(&number)?;
+                number.clear();
+            }
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unexpected character '{}' in ISO 8601 duration",
+                    c
+                )))
+            }
+        }
+    }
+
+    if let Some(time_part) = time_part {
+        let mut number = String::new();
+        for c in time_part.chars() {
+            match c {
+                '0'..='9' | '.' | '-' => number.push(c),
+                'H' => {
+                    total_seconds += parse_duration_number(&number)? * 3600.0;
+                    number.clear();
+                }
+                'M' => {
+                    total_seconds += parse_duration_number(&number)? * 60.0;
+                    number.clear();
+                }
+                'S' => {
+                    total_seconds += parse_duration_number(&number)?;
+                    number.clear();
+                }
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unexpected character '{}' in ISO 8601 duration",
+                        c
+                    )))
+                }
+            }
+        }
+    }
+
+    // Fold any fractional day into seconds, and take the sub-second part
+    // relative to the floored seconds so negative fractions stay exact.
+    let days = total_days.floor();
+    let total_seconds = total_seconds + (total_days - days) * 86_400.0;
+    let seconds = total_seconds.floor();
+    let microseconds = ((total_seconds - seconds) * 1_000_000.0).round() as i32;
+
+    Ok(PyDelta::new(py, days as i32, seconds as i32, microseconds, true)?.into_py(py))
+}
+
+/// Parses a number into an f64, rejecting empty/garbage runs.
+fn parse_duration_number(number: &str) -> PyResult<f64> {
+    number
+        .parse::<f64>()
+        .map_err(|_| PyValueError::new_err("Missing numeric component in ISO 8601 duration"))
+}
+
+/// Parses a PostgreSQL-style interval phrase such as `"1 day 2 hours 30 min"`.
+fn parse_interval_phrase(py: Python, input: &str) -> PyResult<Py<PyDelta>> {
+    let tokens: Vec<&str> = input.split_whitespace().collect();
+    if tokens.len() < 2 || tokens.len() % 2 != 0 {
+        return Err(PyValueError::new_err(format!(
+            "Unable to parse '{}' as an interval. Expected pairs like '1 day 2 hours'",
+            input
+        )));
+    }
+
+    let mut total_days: f64 = 0.0;
+    let mut total_seconds: f64 = 0.0;
+
+    for pair in tokens.chunks(2) {
+        let value: f64 = pair[0]
+            .parse()
+            .map_err(|_| PyValueError::new_err(format!("Invalid numeric value '{}'", pair[0])))?;
+        let lower = pair[1].to_lowercase();
+        let unit = lower.strip_suffix('s').filter(|u| !u.is_empty()).unwrap_or(lower.as_str());
+        match unit {
+            "day" | "d" => total_days += value,
+            "week" | "w" => total_days += value * 7.0,
+            "hour" | "hr" | "h" => total_seconds += value * 3600.0,
+            "min" | "minute" | "m" => total_seconds += value * 60.0,
+            "sec" | "second" | "s" => total_seconds += value,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown interval unit '{}'",
+                    pair[1]
+                )))
+            }
+        }
+    }
+
+    let days = total_days.floor() as i32;
+    let seconds = total_seconds.floor() as i32;
+    let microseconds = ((total_seconds.fract()) * 1_000_000.0).round() as i32;
+
+    Ok(PyDelta::new(py, days, seconds, microseconds, true)?.into_py(py))
+}
+
+/// Autodetects which of the four TOML-style date/time shapes `input` is
+/// (Offset Date-Time, Local Date-Time, Local Date, or Local Time) and
+/// returns the most specific matching Python object, so callers don't need
+/// to know in advance whether a column holds dates, datetimes, or times.
+#[pyfunction]
+fn parse_temporal(py: Python, input: &str) -> PyResult<PyObject> {
+    if input.trim().is_empty() {
+        return Err(PyValueError::new_err("Input string is empty"));
+    }
+
+    // Offset Date-Time / Local Date-Time: both have a date and a time part.
+    if let Ok(parsed) = SpeeDateTime::parse_str(input) {
+        let tzinfo = match parsed.time.tz_offset {
+            Some(offset) => Some(fixed_offset_tzinfo(py, offset)?),
+            None => None,
+        };
+        return Ok(PyDateTime::new(
+            py,
+            parsed.date.year as i32,
+            parsed.date.month,
+            parsed.date.day,
+            parsed.time.hour,
+            parsed.time.minute,
+            parsed.time.second,
+            parsed.time.microsecond,
+            tzinfo.as_ref().map(|tz| tz.as_ref(py)),
+        )?
+        .into_py(py));
+    }
+
+    // Local Date: a date with no time component at all.
+    if let Ok(parsed) = SpeeDate::parse_str(input) {
+        return Ok(PyDate::new(py, parsed.year as i32, parsed.month, parsed.day)?.into_py(py));
+    }
+
+    // Local Time / an offset time with no date component.
+    if let Ok(parsed) = SpeeTime::parse_str(input) {
+        let tzinfo = match parsed.tz_offset {
+            Some(offset) => Some(fixed_offset_tzinfo(py, offset)?),
+            None => None,
+        };
+        return Ok(PyTime::new(
+            py,
+            parsed.hour,
+            parsed.minute,
+            parsed.second,
+            parsed.microsecond,
+            tzinfo.as_ref().map(|tz| tz.as_ref(py)),
+        )?
+        .into_py(py));
+    }
+
+    Err(PyValueError::new_err(format!(
+        "Unable to classify '{}' as a date, datetime, or time",
+        input
+    )))
+}
+
+/// Extracts `(year, month, day, hour, minute, second, microsecond)` from a
+/// `datetime.datetime` or `datetime.date`, for `precise_diff`. A bare date
+/// is treated as midnight.
+fn extract_datetime_components(value: &PyAny) -> PyResult<(i32, u32, u32, u32, u32, u32, u32)> {
+    if let Ok(dt) = value.downcast::<PyDateTime>() {
+        Ok((
+            dt.get_year(),
+            dt.get_month() as u32,
+            dt.get_day() as u32,
+            dt.get_hour() as u32,
+            dt.get_minute() as u32,
+            dt.get_second() as u32,
+            dt.get_microsecond(),
+        ))
+    } else if let Ok(date) = value.downcast::<PyDate>() {
+        Ok((date.get_year(), date.get_month() as u32, date.get_day() as u32, 0, 0, 0, 0))
+    } else {
+        Err(PyValueError::new_err(
+            "precise_diff expects a datetime.datetime or datetime.date",
+        ))
+    }
+}
+
+/// Number of days in `year`-`month`, leap-Februaries included.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    let first_of_this = NaiveDate::from_ymd_opt(year, month, 1).expect("valid month");
+    let first_of_next = NaiveDate::from_ymd_opt(next_year, next_month, 1).expect("valid month");
+    (first_of_next - first_of_this).num_days() as u32
+}
+
+/// Computes `end - start` as a true calendar difference, borrowing from the
+/// next-larger unit (and, when borrowing a day, adding the day count of the
+/// month preceding `end`'s month) instead of collapsing to a flat duration.
+/// `start` must be <= `end`; `precise_diff` handles the swap and sign.
+fn precise_diff_components(
+    start: (i32, u32, u32, u32, u32, u32, u32),
+    end: (i32, u32, u32, u32, u32, u32, u32),
+) -> (i64, i64, i64, i64, i64, i64, i64) {
+    let (y1, mo1, d1, h1, mi1, s1, us1) = start;
+    let (mut y2, mut mo2, mut d2, mut h2, mut mi2, mut s2, us2) = (
+        end.0 as i64, end.1 as i64, end.2 as i64, end.3 as i64, end.4 as i64, end.5 as i64, end.6 as i64,
+    );
+
+    let mut us = us2 - us1 as i64;
+    if us < 0 {
+        us += 1_000_000;
+        s2 -= 1;
+    }
+    let mut s = s2 - s1 as i64;
+    if s < 0 {
+        s += 60;
+        mi2 -= 1;
+    }
+    let mut mi = mi2 - mi1 as i64;
+    if mi < 0 {
+        mi += 60;
+        h2 -= 1;
+    }
+    let mut h = h2 - h1 as i64;
+    if h < 0 {
+        h += 24;
+        d2 -= 1;
+    }
+    // A single month's worth of days isn't always enough: e.g. borrowing
+    // Feb's 28 days to settle Jan 31 -> Mar 1 still leaves `d` negative, so
+    // keep borrowing further months back (at most twice, since no two
+    // consecutive months have fewer than 28+28 days between them) rather
+    // than ever returning a negative day count.
+    let mut d = d2 - d1 as i64;
+    while d < 0 {
+        let (borrow_year, borrow_month) = if mo2 == 1 { (y2 - 1, 12) } else { (y2, mo2 - 1) };
+        d += days_in_month(borrow_year as i32, borrow_month as u32) as i64;
+        y2 = borrow_year;
+        mo2 = borrow_month;
+    }
+    let mut mo = mo2 - mo1 as i64;
+    if mo < 0 {
+        mo += 12;
+        y2 -= 1;
+    }
+    let y = y2 - y1 as i64;
+
+    (y, mo, d, h, mi, s, us)
+}
+
+/// Computes a pendulum-style structured calendar difference between two
+/// `datetime.datetime`/`datetime.date` objects: `years`, `months`, `days`,
+/// `hours`, `minutes`, `seconds`, `microseconds`, plus a `sign` (`1` if
+/// `end >= start`, `-1` otherwise). Unlike a flat `timedelta`, this gives a
+/// human-meaningful breakdown like "2 years, 3 months, 4 days".
+///
+/// # Returns
+/// A `dict` with the fields above.
+#[pyfunction]
+fn precise_diff(py: Python, start: &PyAny, end: &PyAny) -> PyResult<PyObject> {
+    let start_c = extract_datetime_components(start)?;
+    let end_c = extract_datetime_components(end)?;
+
+    let (sign, start_c, end_c) = if end_c < start_c {
+        (-1i32, end_c, start_c)
+    } else {
+        (1i32, start_c, end_c)
+    };
+
+    let (years, months, days, hours, minutes, seconds, microseconds) =
+        precise_diff_components(start_c, end_c);
+
+    let result = PyDict::new(py);
+    result.set_item("years", years)?;
+    result.set_item("months", months)?;
+    result.set_item("days", days)?;
+    result.set_item("hours", hours)?;
+    result.set_item("minutes", minutes)?;
+    result.set_item("seconds", seconds)?;
+    result.set_item("microseconds", microseconds)?;
+    result.set_item("sign", sign)?;
+    Ok(result.into_py(py))
+}
+
+/// Validate every field of a dataclass instance, returning a structured
+/// `ValidationError` for each one that fails instead of a pass/fail mask.
+/// An empty list means the instance is valid. Every field is checked —
+/// one failure doesn't stop the rest from being reported.
+#[pyfunction]
+fn validate_datamodel(py: Python<'_>, dataclass_instance: PyObject) -> PyResult<Vec<ValidationError>> {
     // Get the class of the instance
     let dataclass: &PyType = dataclass_instance.as_ref(py).get_type();
 
     // Get the __dataclass_fields__ attribute from the class
     let fields_dict: &PyDict = dataclass.getattr("__dataclass_fields__")?.downcast::<PyDict>()?;
 
-    // Validate each field in the main thread
-    let results: Vec<(String, bool)> = fields_dict
-        .items()
-        .iter()
-        .map(|item| {
-            let (key, field) = item.extract::<(String, &PyAny)>().unwrap();
+    // Validate each field, recursing into nested dataclasses so failures
+    // are reported against the leaf field (e.g. `"address.zipcode"`)
+    // rather than the top-level field that contains them.
+    let mut errors = Vec::new();
+    for item in fields_dict.items().iter() {
+        let (key, field) = item.extract::<(String, &PyAny)>()?;
+        let type_obj = field.getattr("type")?;
+        let value = dataclass_instance.getattr(py, key.as_str())?;
+        validate_field(py, type_obj, value.as_ref(py), dataclass, &key, &mut errors);
+    }
 
-            // Extract information from the dataclass.Field object
-            let field_type = field.getattr("type").unwrap().to_object(py);
-            let value = dataclass_instance.getattr(py, key.as_str()).unwrap();
+    Ok(errors)
+}
 
-            let is_valid = match validate_field(py, &field_type, &value) {
-                Ok(result) => result,
-                Err(e) => {
-                    eprintln!("Validation error for field {}: {}", key, e);
-                    false
-                }
+/// Classify and validate a single field's annotation, appending a
+/// `ValidationError` to `out` for every leaf that fails — more than one
+/// when `type_obj` classifies as a nested dataclass, none at all if the
+/// field is valid.
+fn validate_field(
+    py: Python<'_>,
+    type_obj: &PyAny,
+    value: &PyAny,
+    owner: &PyAny,
+    path: &str,
+    out: &mut Vec<ValidationError>,
+) {
+    match classify_type(py, type_obj, owner) {
+        Some(ft) => ft.validate_pyobject_paths(py, value, path, out),
+        None => {
+            // Not a container/Optional/nested type we recognize either.
+            let expected = match type_obj.downcast::<PyType>() {
+                Ok(t) => t.name().map(|n| n.to_string()).unwrap_or_else(|_| "<unknown>".to_string()),
+                Err(_) => "<unrecognized annotation>".to_string(),
             };
-            (key.to_string(), is_valid)
-        })
-        .collect();
+            out.push(ValidationError {
+                field: path.rsplit('.').next().unwrap_or(path).to_string(),
+                path: path.to_string(),
+                expected: format!("{} (unsupported)", expected),
+                got: value.get_type().name().map(|n| n.to_string()).unwrap_or_default(),
+            });
+        }
+    }
+}
 
-    Ok(results)
+/// A single field-validation failure, exposed to Python as a proper object
+/// (rather than a bare bool) so callers can build a pydantic-like
+/// aggregated error list for API responses.
+#[pyclass]
+#[derive(Debug, Clone)]
+struct ValidationError {
+    #[pyo3(get)]
+    field: String,
+    #[pyo3(get)]
+    path: String,
+    #[pyo3(get)]
+    expected: String,
+    #[pyo3(get)]
+    got: String,
 }
 
-fn validate_field(py: Python<'_>, field_type: &PyObject, value: &PyObject) -> PyResult<bool> {
-    // Check if it's a primitive type
-    if let Ok(type_) = field_type.extract::<&PyType>(py) {
-        let type_name = type_.name()?;
-        match type_name {
-            "str" => {
-                return Ok(value.extract::<&str>(py).is_ok());
-            }
-            "int" => {
-                return Ok(value.extract::<i64>(py).is_ok());
-            }
-            "float" => {
-                return Ok(value.extract::<f64>(py).is_ok());
-            }
-            "bool" => {
-                return Ok(value.extract::<bool>(py).is_ok());
-            }
-            "datetime" => {
-                return Ok(value.extract::<&PyDateTime>(py).is_ok());
-            }
-            "date" => {
-                return Ok(value.extract::<&PyDate>(py).is_ok());
-            }
-            _ => {
-                // Not a primitive type, you can either skip validation or return an error
-                // eprintln!("Skipping validation for non-primitive type: {}", type_name);
-                // Ok(true) // Option 1: Skip validation
-                return Err(PyTypeError::new_err(format!(
-                    "Validation for type {} is not implemented yet.",
-                    type_name
-                ))); // Option 2: Return an error
-            }
-        }
-    } else {
-        // Handle the case where field_type is not a PyType (e.g., it's a generic type)
-        eprintln!("Field type is not a PyType: {:?}", field_type);
-        return Err(PyTypeError::new_err(
-            "Field type is not a PyType, cannot validate.",
-        ));
+#[pymethods]
+impl ValidationError {
+    fn __repr__(&self) -> String {
+        format!(
+            "ValidationError(field={:?}, path={:?}, expected={:?}, got={:?})",
+            self.field, self.path, self.expected, self.got
+        )
     }
 }
 
@@ -300,11 +1268,30 @@ enum FieldType {
     DateTime,
     Date,
     Time,
+    Uuid,
+    Decimal,
+    Bytes,
+    Optional(Box<FieldType>),
+    List(Box<FieldType>),
+    Dict(Box<FieldType>, Box<FieldType>),
+    Tuple(Vec<FieldType>),
+    Nested(PyObject),
+    /// A `Union` of dataclasses tagged by a discriminator field, mapping
+    /// each literal tag value to its concrete member class. Letting
+    /// validation jump straight to the matching variant instead of trying
+    /// every member in turn.
+    Discriminated(String, HashMap<String, PyObject>),
+    /// An annotation `classify_type` couldn't resolve. Carries the raw type
+    /// name so `describe`/`ValidationError` can report it, same wording as
+    /// `validate_field`'s `"(unsupported)"` case; always fails `parse`/
+    /// `validate`/`coerce` so `parse_datamodel`/`coerce_datamodel` agree
+    /// with `validate_datamodel` instead of silently skipping the field.
+    Unsupported(String),
     // Extend with more types as needed
 }
 
 impl FieldType {
-    /// Convert type name string to FieldType enum
+    /// Convert a scalar type name string to a FieldType enum
     fn from_str(type_name: &str) -> Option<Self> {
         match type_name {
             "str" => Some(FieldType::Str),
@@ -314,12 +1301,57 @@ impl FieldType {
             "datetime.datetime" => Some(FieldType::DateTime),
             "datetime.date" => Some(FieldType::Date),
             "datetime.time" => Some(FieldType::Time),
+            "uuid.UUID" => Some(FieldType::Uuid),
+            "decimal.Decimal" => Some(FieldType::Decimal),
+            "bytes" => Some(FieldType::Bytes),
             _ => None,
         }
     }
 
-    /// Parse the string representation into Rust-native types if necessary
+    /// A human-readable description of this FieldType, used as the
+    /// `expected` side of a `ValidationError`.
+    fn describe(&self) -> String {
+        match self {
+            FieldType::Str => "str".to_string(),
+            FieldType::Int => "int".to_string(),
+            FieldType::Float => "float".to_string(),
+            FieldType::Bool => "bool".to_string(),
+            FieldType::DateTime => "datetime.datetime".to_string(),
+            FieldType::Date => "datetime.date".to_string(),
+            FieldType::Time => "datetime.time".to_string(),
+            FieldType::Uuid => "uuid.UUID".to_string(),
+            FieldType::Decimal => "decimal.Decimal".to_string(),
+            FieldType::Bytes => "bytes".to_string(),
+            FieldType::Optional(inner) => format!("Optional[{}]", inner.describe()),
+            FieldType::List(inner) => format!("list[{}]", inner.describe()),
+            FieldType::Dict(key_type, value_type) => {
+                format!("dict[{}, {}]", key_type.describe(), value_type.describe())
+            }
+            FieldType::Tuple(types) => format!(
+                "tuple[{}]",
+                types.iter().map(FieldType::describe).collect::<Vec<_>>().join(", ")
+            ),
+            FieldType::Nested(_) => "dataclass".to_string(),
+            FieldType::Discriminated(discriminator, _) => {
+                format!("Union (discriminator={})", discriminator)
+            }
+            FieldType::Unsupported(name) => format!("{} (unsupported)", name),
+        }
+    }
+
+    /// Parse the string representation into Rust-native types if necessary,
+    /// recursing into containers, Optionals, and nested dataclasses.
     fn parse(&self, value: &FieldValue) -> bool {
+        if matches!(value, FieldValue::None) {
+            return matches!(self, FieldType::Optional(_));
+        }
+        // `extract_value` keeps date/time strings in their own variants.
+        match (self, value) {
+            (FieldType::DateTime, FieldValue::DateTime(s)) => return datetime_str_is_parseable(s),
+            (FieldType::Date, FieldValue::Date(s)) => return date_str_is_parseable(s),
+            (FieldType::Time, FieldValue::Time(s)) => return time_str_is_parseable(s),
+            _ => {}
+        }
         match self {
             FieldType::Str => true, // Already a string
             FieldType::Int => true, // Already an integer
@@ -327,40 +1359,382 @@ impl FieldType {
             FieldType::Bool => true, // Already a bool
             FieldType::DateTime => {
                 if let FieldValue::Str(s) = value {
-                    DateTime::parse_from_rfc3339(s).is_ok()
+                    datetime_str_is_parseable(s)
                 } else {
                     false
                 }
             },
             FieldType::Date => {
                 if let FieldValue::Str(s) = value {
-                    NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok()
+                    date_str_is_parseable(s)
                 } else {
                     false
                 }
             },
             FieldType::Time => {
                 if let FieldValue::Str(s) = value {
-                    NaiveTime::parse_from_str(s, "%H:%M:%S").is_ok()
+                    time_str_is_parseable(s)
                 } else {
                     false
                 }
             },
-            // Implement other parsing as needed
+            FieldType::Uuid => matches!(value, FieldValue::Uuid(s) if Uuid::parse_str(s).is_ok()),
+            FieldType::Decimal => matches!(value, FieldValue::Decimal(s) if Decimal::from_str(s).is_ok()),
+            FieldType::Bytes => matches!(value, FieldValue::Bytes(_)),
+            FieldType::Optional(inner) => inner.parse(value),
+            FieldType::List(inner) => match value {
+                FieldValue::List(items) => items.iter().all(|item| inner.parse(item)),
+                _ => false,
+            },
+            FieldType::Dict(key_type, value_type) => match value {
+                FieldValue::Dict(items) => items
+                    .iter()
+                    .all(|(k, v)| key_type.parse(k) && value_type.parse(v)),
+                _ => false,
+            },
+            FieldType::Tuple(types) => match value {
+                FieldValue::Tuple(items) => {
+                    items.len() == types.len() && types.iter().zip(items).all(|(ty, val)| ty.parse(val))
+                }
+                _ => false,
+            },
+            FieldType::Nested(_) => match value {
+                FieldValue::Nested(fields) => fields.iter().all(|(_, ty, val)| ty.parse(val)),
+                _ => false,
+            },
+            FieldType::Discriminated(_, variants) => match value {
+                FieldValue::Discriminated(tag, fields) => {
+                    variants.contains_key(tag) && fields.iter().all(|(_, ty, val)| ty.parse(val))
+                }
+                _ => false,
+            },
+            FieldType::Unsupported(_) => false,
         }
     }
 
-    /// Validate the PyObject against the FieldType
+    /// Validate the `FieldValue` against the FieldType, recursing into
+    /// containers, Optionals, and nested dataclasses.
     fn validate(&self, value: &FieldValue) -> bool {
+        if let FieldType::Optional(inner) = self {
+            return matches!(value, FieldValue::None) || inner.validate(value);
+        }
+        match (self, value) {
+            (FieldType::Str, FieldValue::Str(_)) => true,
+            (FieldType::Int, FieldValue::Int(_)) => true,
+            (FieldType::Float, FieldValue::Float(_)) => true,
+            (FieldType::Bool, FieldValue::Bool(_)) => true,
+            (FieldType::DateTime, FieldValue::DateTime(_)) => true,
+            (FieldType::Date, FieldValue::Date(_)) => true,
+            (FieldType::Time, FieldValue::Time(_)) => true,
+            (FieldType::Uuid, FieldValue::Uuid(_)) => true,
+            (FieldType::Decimal, FieldValue::Decimal(_)) => true,
+            (FieldType::Bytes, FieldValue::Bytes(_)) => true,
+            (FieldType::List(inner), FieldValue::List(items)) => {
+                items.iter().all(|item| inner.validate(item))
+            }
+            (FieldType::Dict(key_type, value_type), FieldValue::Dict(items)) => items
+                .iter()
+                .all(|(k, v)| key_type.validate(k) && value_type.validate(v)),
+            (FieldType::Tuple(types), FieldValue::Tuple(items)) => {
+                types.len() == items.len() && types.iter().zip(items).all(|(ty, val)| ty.validate(val))
+            }
+            (FieldType::Nested(_), FieldValue::Nested(fields)) => {
+                fields.iter().all(|(_, ty, val)| ty.validate(val))
+            }
+            (FieldType::Discriminated(_, variants), FieldValue::Discriminated(tag, fields)) => {
+                variants.contains_key(tag) && fields.iter().all(|(_, ty, val)| ty.validate(val))
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether a live `PyAny` holds a value of a scalar `FieldType`
+    /// (everything except the container/Nested/Discriminated variants,
+    /// which `validate_pyobject_paths` handles itself so it can report a
+    /// structured `ValidationError` instead of a bare bool).
+    fn validate_scalar_pyobject(&self, value: &PyAny) -> bool {
+        match self {
+            FieldType::Str => value.extract::<&str>().is_ok(),
+            FieldType::Int => value.extract::<i64>().is_ok(),
+            FieldType::Float => value.extract::<f64>().is_ok(),
+            FieldType::Bool => value.extract::<bool>().is_ok(),
+            FieldType::DateTime => value.extract::<&PyDateTime>().is_ok(),
+            FieldType::Date => value.extract::<&PyDate>().is_ok(),
+            FieldType::Time => value.extract::<&PyTime>().is_ok(),
+            FieldType::Uuid => match value.extract::<&str>() {
+                Ok(s) => Uuid::parse_str(s).is_ok(),
+                Err(_) => value.get_type().name().map(|n| n == "UUID").unwrap_or(false),
+            },
+            FieldType::Decimal => match value.extract::<&str>() {
+                Ok(s) => Decimal::from_str(s).is_ok(),
+                Err(_) => value.get_type().name().map(|n| n == "Decimal").unwrap_or(false),
+            },
+            FieldType::Bytes => value.extract::<Vec<u8>>().is_ok(),
+            _ => false, // Containers/Nested/Discriminated/Optional never reach here.
+        }
+    }
+
+    /// Like `validate_scalar_pyobject`, but walks into nested dataclasses instead
+    /// of collapsing them into a single pass/fail, and reports a
+    /// `ValidationError` (rather than a bare bool) for each leaf field that
+    /// fails, dotted to the path that failed (e.g. `"address.zipcode"`)
+    /// rather than just `"address"`. Fields that validate successfully are
+    /// not reported at all — an empty `out` means the model is valid.
+    fn validate_pyobject_paths(&self, py: Python<'_>, value: &PyAny, path: &str, out: &mut Vec<ValidationError>) {
+        if let FieldType::Optional(inner) = self {
+            if !value.is_none() {
+                inner.validate_pyobject_paths(py, value, path, out);
+            }
+            return;
+        }
+        let field = path.rsplit('.').next().unwrap_or(path).to_string();
+        let got_type = |value: &PyAny| value.get_type().name().map(|n| n.to_string()).unwrap_or_default();
         match self {
-            FieldType::Str => matches!(value, FieldValue::Str(_)),
-            FieldType::Int => matches!(value, FieldValue::Int(_)),
-            FieldType::Float => matches!(value, FieldValue::Float(_)),
-            FieldType::Bool => matches!(value, FieldValue::Bool(_)),
-            FieldType::DateTime => matches!(value, FieldValue::DateTime(_)),
-            FieldType::Date => matches!(value, FieldValue::Date(_)),
-            FieldType::Time => matches!(value, FieldValue::Time(_)),
-            // Add more validations as needed
+            FieldType::List(inner) => match value.downcast::<pyo3::types::PyList>() {
+                Ok(list) => {
+                    for (i, item) in list.iter().enumerate() {
+                        inner.validate_pyobject_paths(py, item, &format!("{}[{}]", path, i), out);
+                    }
+                }
+                Err(_) => out.push(ValidationError {
+                    field,
+                    path: path.to_string(),
+                    expected: self.describe(),
+                    got: got_type(value),
+                }),
+            },
+            FieldType::Dict(key_type, value_type) => match value.downcast::<PyDict>() {
+                Ok(dict) => {
+                    for (k, v) in dict.iter() {
+                        let key_repr = k.str().map(|s| s.to_string()).unwrap_or_default();
+                        let before = out.len();
+                        key_type.validate_pyobject_paths(py, k, &format!("{}[{}] (key)", path, key_repr), out);
+                        if out.len() > before {
+                            continue;
+                        }
+                        value_type.validate_pyobject_paths(py, v, &format!("{}[{}]", path, key_repr), out);
+                    }
+                }
+                Err(_) => out.push(ValidationError {
+                    field,
+                    path: path.to_string(),
+                    expected: self.describe(),
+                    got: got_type(value),
+                }),
+            },
+            FieldType::Tuple(types) => match value.downcast::<pyo3::types::PyTuple>() {
+                Ok(tuple) if tuple.len() == types.len() => {
+                    for (i, (ty, item)) in types.iter().zip(tuple.iter()).enumerate() {
+                        ty.validate_pyobject_paths(py, item, &format!("{}[{}]", path, i), out);
+                    }
+                }
+                _ => out.push(ValidationError {
+                    field,
+                    path: path.to_string(),
+                    expected: self.describe(),
+                    got: got_type(value),
+                }),
+            },
+            FieldType::Nested(nested_type) => {
+                if !value.get_type().is(nested_type.as_ref(py)) {
+                    out.push(ValidationError {
+                        field,
+                        path: path.to_string(),
+                        expected: self.describe(),
+                        got: got_type(value),
+                    });
+                    return;
+                }
+                let fields_dict = match nested_type
+                    .as_ref(py)
+                    .getattr("__dataclass_fields__")
+                    .and_then(|f| f.downcast::<PyDict>().map_err(PyErr::from))
+                {
+                    Ok(fields_dict) => fields_dict,
+                    Err(_) => {
+                        out.push(ValidationError {
+                            field,
+                            path: path.to_string(),
+                            expected: self.describe(),
+                            got: got_type(value),
+                        });
+                        return;
+                    }
+                };
+                for (key, field_obj) in fields_dict.iter() {
+                    let name = match key.extract::<String>() {
+                        Ok(n) => n,
+                        Err(_) => continue,
+                    };
+                    let nested_path = format!("{}.{}", path, name);
+                    let nested_value = match value.getattr(&name[..]) {
+                        Ok(v) => v,
+                        Err(_) => {
+                            out.push(ValidationError {
+                                field: name,
+                                path: nested_path,
+                                expected: "<present>".to_string(),
+                                got: "<missing>".to_string(),
+                            });
+                            continue;
+                        }
+                    };
+                    let type_obj = match field_obj.getattr("type") {
+                        Ok(t) => t,
+                        Err(_) => continue,
+                    };
+                    if let Some(nested_ft) = classify_type(py, type_obj, nested_type.as_ref(py)) {
+                        nested_ft.validate_pyobject_paths(py, nested_value, &nested_path, out);
+                    } // Unrecognized nested field type: nothing to check.
+                }
+            }
+            FieldType::Discriminated(discriminator, variants) => {
+                let tag = match value.getattr(discriminator.as_str()).and_then(|t| t.extract::<String>()) {
+                    Ok(t) => t,
+                    Err(_) => {
+                        out.push(ValidationError {
+                            field,
+                            path: path.to_string(),
+                            expected: self.describe(),
+                            got: got_type(value),
+                        });
+                        return;
+                    }
+                };
+                match variants.get(&tag) {
+                    Some(variant_type) => FieldType::Nested(variant_type.clone_ref(py))
+                        .validate_pyobject_paths(py, value, path, out),
+                    None => out.push(ValidationError {
+                        field,
+                        path: path.to_string(),
+                        expected: self.describe(),
+                        got: format!("unmatched discriminator '{}'", tag),
+                    }),
+                }
+            }
+            _ => {
+                if !self.validate_scalar_pyobject(value) {
+                    out.push(ValidationError {
+                        field,
+                        path: path.to_string(),
+                        expected: self.describe(),
+                        got: got_type(value),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Coerce a `FieldValue` into the native Python object it represents,
+    /// reusing the `to_date`/`to_datetime`/`to_time` converters so a
+    /// validated field's *value* is kept instead of being thrown away.
+    /// Returns `Ok(None)` (not an error) when coercion fails, so the caller
+    /// can report it as a per-field failure.
+    fn coerce(&self, py: Python<'_>, value: &FieldValue) -> PyResult<Option<PyObject>> {
+        if let FieldValue::None = value {
+            return Ok(match self {
+                FieldType::Optional(_) => Some(py.None()),
+                _ => None,
+            });
+        }
+        match (self, value) {
+            (FieldType::Str, FieldValue::Str(s)) => Ok(Some(s.into_py(py))),
+            (FieldType::Int, FieldValue::Int(i)) => Ok(Some(i.into_py(py))),
+            (FieldType::Float, FieldValue::Float(f)) => Ok(Some(f.into_py(py))),
+            (FieldType::Bool, FieldValue::Bool(b)) => Ok(Some(b.into_py(py))),
+            (FieldType::DateTime, FieldValue::DateTime(s)) => {
+                Ok(to_datetime(py, s, None, false, None, None, false).ok().map(|v| v.into_py(py)))
+            }
+            (FieldType::Date, FieldValue::Date(s)) => {
+                Ok(to_date(py, s, None, None, false, false, false).ok().map(|v| v.into_py(py)))
+            }
+            (FieldType::Time, FieldValue::Time(s)) => {
+                Ok(to_time(py, s, None).ok().map(|v| v.into_py(py)))
+            }
+            (FieldType::Uuid, FieldValue::Uuid(s)) => match Uuid::parse_str(s) {
+                Ok(uuid) => {
+                    let uuid_cls = py.import("uuid")?.getattr("UUID")?;
+                    Ok(Some(uuid_cls.call1((uuid.to_string(),))?.into_py(py)))
+                }
+                Err(_) => Ok(None),
+            },
+            (FieldType::Decimal, FieldValue::Decimal(s)) => match Decimal::from_str(s) {
+                Ok(decimal) => {
+                    let decimal_cls = py.import("decimal")?.getattr("Decimal")?;
+                    Ok(Some(decimal_cls.call1((decimal.to_string(),))?.into_py(py)))
+                }
+                Err(_) => Ok(None),
+            },
+            (FieldType::Bytes, FieldValue::Bytes(b)) => {
+                Ok(Some(pyo3::types::PyBytes::new(py, b).into_py(py)))
+            }
+            (FieldType::Optional(inner), _) => inner.coerce(py, value),
+            (FieldType::List(inner), FieldValue::List(items)) => {
+                let mut coerced = Vec::with_capacity(items.len());
+                for item in items {
+                    match inner.coerce(py, item)? {
+                        Some(obj) => coerced.push(obj),
+                        None => return Ok(None),
+                    }
+                }
+                Ok(Some(pyo3::types::PyList::new(py, coerced).into_py(py)))
+            }
+            (FieldType::Dict(key_type, value_type), FieldValue::Dict(items)) => {
+                let result = PyDict::new(py);
+                for (k, v) in items {
+                    let key_obj = match key_type.coerce(py, k)? {
+                        Some(obj) => obj,
+                        None => return Ok(None),
+                    };
+                    let value_obj = match value_type.coerce(py, v)? {
+                        Some(obj) => obj,
+                        None => return Ok(None),
+                    };
+                    result.set_item(key_obj, value_obj)?;
+                }
+                Ok(Some(result.into_py(py)))
+            }
+            (FieldType::Tuple(types), FieldValue::Tuple(items)) => {
+                if types.len() != items.len() {
+                    return Ok(None);
+                }
+                let mut coerced = Vec::with_capacity(items.len());
+                for (ty, item) in types.iter().zip(items) {
+                    match ty.coerce(py, item)? {
+                        Some(obj) => coerced.push(obj),
+                        None => return Ok(None),
+                    }
+                }
+                Ok(Some(pyo3::types::PyTuple::new(py, coerced).into_py(py)))
+            }
+            (FieldType::Nested(_), FieldValue::Nested(fields)) => {
+                let result = PyDict::new(py);
+                for (name, ty, val) in fields {
+                    match ty.coerce(py, val)? {
+                        Some(obj) => result.set_item(name, obj)?,
+                        None => return Ok(None),
+                    }
+                }
+                Ok(Some(result.into_py(py)))
+            }
+            (FieldType::Discriminated(_, variants), FieldValue::Discriminated(tag, fields)) => {
+                // `extract_value` represents an unmatched tag as an empty
+                // `fields` Vec (see its `Discriminated` arm); treat that the
+                // same way `parse`/`validate` do and fail closed instead of
+                // coercing it into a misleading empty dict.
+                if !variants.contains_key(tag) {
+                    return Ok(None);
+                }
+                let result = PyDict::new(py);
+                for (name, ty, val) in fields {
+                    match ty.coerce(py, val)? {
+                        Some(obj) => result.set_item(name, obj)?,
+                        None => return Ok(None),
+                    }
+                }
+                Ok(Some(result.into_py(py)))
+            }
+            _ => Ok(None),
         }
     }
 }
@@ -375,9 +1749,41 @@ enum FieldValue {
     DateTime(String), // Store as String; parse validation done separately
     Date(String),
     Time(String),
+    Uuid(String),
+    Decimal(String),
+    Bytes(Vec<u8>),
+    None,
+    List(Vec<FieldValue>),
+    Dict(Vec<(FieldValue, FieldValue)>),
+    Tuple(Vec<FieldValue>),
+    Nested(Vec<(String, FieldType, FieldValue)>),
+    Discriminated(String, Vec<(String, FieldType, FieldValue)>),
     // Extend with more types as needed
 }
 
+impl FieldValue {
+    /// A human-readable description of the shape this value was extracted
+    /// as, used as the `got` side of a `ValidationError` where we don't
+    /// hold the GIL to ask the original `PyObject` for its real type name.
+    fn describe(&self) -> &'static str {
+        match self {
+            FieldValue::Str(_) => "str",
+            // Stored as the raw source string pending a successful `parse`.
+            FieldValue::DateTime(_) | FieldValue::Date(_) | FieldValue::Time(_) => "str",
+            FieldValue::Uuid(_) | FieldValue::Decimal(_) => "str",
+            FieldValue::Int(_) => "int",
+            FieldValue::Float(_) => "float",
+            FieldValue::Bool(_) => "bool",
+            FieldValue::Bytes(_) => "bytes",
+            FieldValue::None => "NoneType",
+            FieldValue::List(_) => "list",
+            FieldValue::Dict(_) => "dict",
+            FieldValue::Tuple(_) => "tuple",
+            FieldValue::Nested(_) | FieldValue::Discriminated(_, _) => "dataclass",
+        }
+    }
+}
+
 // A Rust struct representing the minimal info we need from each dataclass Field
 #[derive(Debug)]
 struct RustFieldInfo {
@@ -385,62 +1791,342 @@ struct RustFieldInfo {
     pub field_type: FieldType,
     pub type_name: String, // Assuming type is always present for simplicity
     value: FieldValue,
+    /// The value straight off the instance, kept around for
+    /// `custom_coercion` since that path needs the GIL to call back into
+    /// Python rather than the pre-extracted `FieldValue`.
+    raw_value: PyObject,
+    /// A user-registered `encoder`/`decoder`/`from_py_with` callable from
+    /// `dataclasses.field(metadata={...})`, overriding the default
+    /// from-Python conversion for this field.
+    custom_coercion: Option<PyObject>,
+}
+
+/// Look up a `dataclasses.Field`'s `metadata` for a custom coercion
+/// callable, checked under the `"decoder"`, `"encoder"`, or `"from_py_with"`
+/// keys (first one present wins), analogous to PyO3's `from_py_with`.
+/// `Field.metadata` is a `types.MappingProxyType`, not a `dict`, so this
+/// reads it through the generic mapping protocol (`__getitem__`) rather
+/// than downcasting to `PyDict`, which would never succeed.
+fn custom_coercion_of(field_obj: &PyAny) -> Option<PyObject> {
+    let metadata = field_obj.getattr("metadata").ok()?;
+    let callable = ["decoder", "encoder", "from_py_with"]
+        .iter()
+        .find_map(|key| metadata.get_item(key).ok())?;
+    if callable.is_callable() {
+        Some(callable.into())
+    } else {
+        None
+    }
+}
+
+/// Resolve a PEP 563 string annotation or a `typing.ForwardRef` against the
+/// owning class's module globals, mirroring how a compiler's symbol
+/// resolver defers analysis until the referenced name actually exists.
+/// Any other annotation is returned unchanged.
+fn resolve_annotation<'py>(py: Python<'py>, type_obj: &'py PyAny, owner: &'py PyAny) -> PyResult<&'py PyAny> {
+    let forward_arg = if let Ok(name) = type_obj.extract::<String>() {
+        Some(name)
+    } else if type_obj.hasattr("__forward_arg__").unwrap_or(false) {
+        Some(type_obj.getattr("__forward_arg__")?.extract::<String>()?)
+    } else {
+        None
+    };
+
+    match forward_arg {
+        Some(annotation) => {
+            let module_name: String = owner.getattr("__module__")?.extract()?;
+            let globals = py
+                .import("sys")?
+                .getattr("modules")?
+                .get_item(module_name)?
+                .getattr("__dict__")?;
+            py.import("builtins")?.call_method1("eval", (annotation, globals))
+        }
+        None => Ok(type_obj),
+    }
+}
+
+/// Classify a field's annotation into a `FieldType`, recursing into
+/// `typing` generics (`Optional`, `list[...]`, `dict[...]`) and nested
+/// dataclasses. `owner` is the class the annotation was declared on, used
+/// to resolve `ForwardRef`/string annotations against its module globals.
+/// Returns `None` for annotations we don't understand yet.
+fn classify_type(py: Python<'_>, type_obj: &PyAny, owner: &PyAny) -> Option<FieldType> {
+    let type_obj = resolve_annotation(py, type_obj, owner).ok()?;
+
+    // A plain class: either a known scalar, or a nested dataclass.
+    if let Ok(type_) = type_obj.downcast::<PyType>() {
+        if let Ok(name) = type_.name() {
+            // `from_str` keys non-builtin types by `module.name` ("datetime.date").
+            let module = type_
+                .getattr("__module__")
+                .and_then(|m| m.extract::<String>())
+                .unwrap_or_default();
+            let qualified = if module == "builtins" { name.to_string() } else { format!("{}.{}", module, name) };
+            if let Some(scalar) = FieldType::from_str(&qualified) {
+                return Some(scalar);
+            }
+        }
+        if type_obj.hasattr("__dataclass_fields__").unwrap_or(false) {
+            return Some(FieldType::Nested(type_obj.into_py(py)));
+        }
+        return None;
+    }
+
+    // Otherwise this should be a `typing` generic alias: inspect its origin/args.
+    let typing = py.import("typing").ok()?;
+    let origin = typing.call_method1("get_origin", (type_obj,)).ok()?;
+    if origin.is_none() {
+        return None;
+    }
+    let args = typing
+        .call_method1("get_args", (type_obj,))
+        .ok()?
+        .downcast::<pyo3::types::PyTuple>()
+        .ok()?;
+
+    let origin_name = origin
+        .getattr("__name__")
+        .ok()
+        .and_then(|n| n.extract::<String>().ok());
+
+    match origin_name.as_deref() {
+        Some("list") => {
+            let inner = classify_type(py, args.get_item(0).ok()?, owner)?;
+            Some(FieldType::List(Box::new(inner)))
+        }
+        Some("dict") => {
+            let key = classify_type(py, args.get_item(0).ok()?, owner)?;
+            let value = classify_type(py, args.get_item(1).ok()?, owner)?;
+            Some(FieldType::Dict(Box::new(key), Box::new(value)))
+        }
+        // `set`/`frozenset` don't have a dedicated variant: validate each
+        // member like a `list` would.
+        Some("set") | Some("frozenset") => {
+            let inner = classify_type(py, args.get_item(0).ok()?, owner)?;
+            Some(FieldType::List(Box::new(inner)))
+        }
+        Some("tuple") => {
+            // `Tuple[int, ...]` (variadic) has no fixed arity, which
+            // `FieldType::Tuple` can't express: treat it as unsupported
+            // rather than silently mis-classifying it as a 1-tuple.
+            let is_variadic = args
+                .iter()
+                .any(|a| a.get_type().name().ok().as_deref() == Some("ellipsis"));
+            if is_variadic {
+                return None;
+            }
+            let member_types = args
+                .iter()
+                .map(|a| classify_type(py, a, owner))
+                .collect::<Option<Vec<_>>>()?;
+            Some(FieldType::Tuple(member_types))
+        }
+        _ => {
+            // `Optional[T]` is `Union[T, None]`: the origin has no __name__.
+            let none = py.None();
+            let none_type = none.as_ref(py).get_type();
+            let is_union_with_none = args.iter().any(|a| a.is(none_type));
+            if is_union_with_none && args.len() == 2 {
+                let inner = args.iter().find(|a| !a.is(none_type))?;
+                return Some(FieldType::Optional(Box::new(classify_type(py, inner, owner)?)));
+            }
+
+            // A `Union` of dataclasses annotated with `__discriminator__`
+            // gets the O(1) tagged-union fast path instead of trying each
+            // member in turn.
+            let members: Vec<&PyAny> = args.iter().filter(|a| !a.is(none_type)).collect();
+            if members.len() > 1
+                && members
+                    .iter()
+                    .all(|m| m.hasattr("__dataclass_fields__").unwrap_or(false))
+            {
+                if let Some(discriminator) = members[0]
+                    .getattr("__discriminator__")
+                    .ok()
+                    .and_then(|d| d.extract::<String>().ok())
+                {
+                    let mut tags: HashMap<String, PyObject> = HashMap::new();
+                    for member in &members {
+                        if let Ok(tag_value) = member.getattr(discriminator.as_str()) {
+                            if let Ok(tag) = tag_value.extract::<String>() {
+                                tags.insert(tag, member.into_py(py));
+                            } else if let Ok(aliases) = tag_value.extract::<Vec<String>>() {
+                                for tag in aliases {
+                                    tags.insert(tag, member.into_py(py));
+                                }
+                            }
+                        }
+                    }
+                    if !tags.is_empty() {
+                        return Some(FieldType::Discriminated(discriminator, tags));
+                    }
+                }
+            }
+
+            None
+        }
+    }
+}
+
+/// Recursively pull a Python value into its native `FieldValue`
+/// representation, following the shape described by `field_type`.
+fn extract_value(py: Python<'_>, field_type: &FieldType, py_value: &PyAny) -> PyResult<FieldValue> {
+    if py_value.is_none() {
+        return Ok(FieldValue::None);
+    }
+    match field_type {
+        FieldType::Str => Ok(FieldValue::Str(py_value.extract::<String>()?)),
+        FieldType::Int => Ok(FieldValue::Int(py_value.extract::<i64>()?)),
+        FieldType::Float => Ok(FieldValue::Float(py_value.extract::<f64>()?)),
+        FieldType::Bool => Ok(FieldValue::Bool(py_value.extract::<bool>()?)),
+        // Accept either the native object (`datetime`/`date`/`time`) or its
+        // string form, same as the Uuid/Decimal arms below: `str()` on a
+        // native value round-trips through `to_datetime`/`to_date`/`to_time`
+        // just like a user-supplied string would.
+        FieldType::DateTime => Ok(FieldValue::DateTime(match py_value.extract::<&PyDateTime>() {
+            Ok(_) => py_value.str()?.to_string(),
+            Err(_) => py_value.extract::<String>()?,
+        })),
+        FieldType::Date => Ok(FieldValue::Date(match py_value.extract::<&PyDate>() {
+            Ok(_) => py_value.str()?.to_string(),
+            Err(_) => py_value.extract::<String>()?,
+        })),
+        FieldType::Time => Ok(FieldValue::Time(match py_value.extract::<&PyTime>() {
+            Ok(_) => py_value.str()?.to_string(),
+            Err(_) => py_value.extract::<String>()?,
+        })),
+        // Accept either the native object (`uuid.UUID`/`decimal.Decimal`)
+        // or its string form; either way we keep the string representation
+        // and defer real parsing to `parse`/`coerce`.
+        FieldType::Uuid | FieldType::Decimal => match py_value.extract::<String>() {
+            Ok(s) => Ok(if matches!(field_type, FieldType::Uuid) {
+                FieldValue::Uuid(s)
+            } else {
+                FieldValue::Decimal(s)
+            }),
+            Err(_) => {
+                let s = py_value.str()?.to_string();
+                Ok(if matches!(field_type, FieldType::Uuid) {
+                    FieldValue::Uuid(s)
+                } else {
+                    FieldValue::Decimal(s)
+                })
+            }
+        },
+        FieldType::Bytes => Ok(FieldValue::Bytes(py_value.extract::<Vec<u8>>()?)),
+        FieldType::Optional(inner) => extract_value(py, inner, py_value),
+        FieldType::List(inner) => {
+            let list = py_value.downcast::<pyo3::types::PyList>()?;
+            let items = list
+                .iter()
+                .map(|item| extract_value(py, inner, item))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(FieldValue::List(items))
+        }
+        FieldType::Dict(key_type, value_type) => {
+            let dict = py_value.downcast::<PyDict>()?;
+            let mut items = Vec::new();
+            for (k, v) in dict.iter() {
+                items.push((extract_value(py, key_type, k)?, extract_value(py, value_type, v)?));
+            }
+            Ok(FieldValue::Dict(items))
+        }
+        FieldType::Tuple(types) => {
+            let tuple = py_value.downcast::<pyo3::types::PyTuple>()?;
+            let items = types
+                .iter()
+                .zip(tuple.iter())
+                .map(|(ty, item)| extract_value(py, ty, item))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(FieldValue::Tuple(items))
+        }
+        FieldType::Nested(nested_type) => {
+            let fields_dict: &PyDict = nested_type
+                .as_ref(py)
+                .getattr("__dataclass_fields__")?
+                .downcast::<PyDict>()?;
+            let mut fields = Vec::new();
+            for (key, field_obj) in fields_dict.iter() {
+                let field_name = key.extract::<String>()?;
+                let type_obj = field_obj.getattr("type")?;
+                let nested_field_type = match classify_type(py, type_obj, nested_type.as_ref(py)) {
+                    Some(ft) => ft,
+                    None => continue,
+                };
+                let nested_value = py_value.getattr(&field_name[..])?;
+                let value = extract_value(py, &nested_field_type, nested_value)?;
+                fields.push((field_name, nested_field_type, value));
+            }
+            Ok(FieldValue::Nested(fields))
+        }
+        FieldType::Discriminated(discriminator, variants) => {
+            let tag = py_value.getattr(discriminator.as_str())?.extract::<String>()?;
+            match variants.get(&tag) {
+                Some(variant_type) => {
+                    match extract_value(py, &FieldType::Nested(variant_type.clone_ref(py)), py_value)? {
+                        FieldValue::Nested(fields) => Ok(FieldValue::Discriminated(tag, fields)),
+                        other => Ok(other),
+                    }
+                }
+                // No variant matches the tag: let `parse`/`validate` report
+                // it as a per-field `ValidationError` (same as
+                // `validate_pyobject_paths` does for this case) instead of
+                // raising and aborting the whole `get_field_info` call.
+                None => Ok(FieldValue::Discriminated(tag, Vec::new())),
+            }
+        }
+        // Carries no extractable shape; `parse`/`validate`/`coerce` always
+        // fail for it, which is how we surface the `ValidationError`.
+        FieldType::Unsupported(_) => Ok(FieldValue::None),
+    }
 }
 
 /// Collect the minimal field data we need into native Rust structs
 fn get_field_info(py: Python<'_>, dataclass_instance: &PyObject, fields_dict: &PyDict) -> PyResult<Vec<RustFieldInfo>> {
+    let owner: &PyType = dataclass_instance.as_ref(py).get_type();
     let mut result = Vec::new();
 
     for (key, field_obj) in fields_dict.iter() {
         let field_name = key.extract::<String>()?;
-
-        // Extract type name
         let type_obj = field_obj.getattr("type")?;
-        let type_name = type_obj.extract::<&PyType>()?.name()?.to_string();
+        let type_name = type_obj.str()?.to_string();
+        let py_value = dataclass_instance.getattr(py, &field_name[..])?;
+
+        // A field with a registered encoder/decoder skips our scalar/
+        // container classification entirely: the callable owns coercion.
+        if let Some(custom_coercion) = custom_coercion_of(field_obj) {
+            result.push(RustFieldInfo {
+                field_name,
+                field_type: FieldType::Str,
+                type_name,
+                value: FieldValue::None,
+                raw_value: py_value,
+                custom_coercion: Some(custom_coercion),
+            });
+            continue;
+        }
 
-        // Convert type name to FieldType enum
-        let field_type = match FieldType::from_str(&type_name) {
+        // Classify the annotation, recursing into generics/nested dataclasses.
+        // An annotation we can't classify still gets a `RustFieldInfo`, as
+        // `FieldType::Unsupported`, so it surfaces as a `ValidationError`
+        // instead of silently vanishing from `parse_datamodel`'s results
+        // (which `validate_datamodel` would have flagged for the same field).
+        let field_type = match classify_type(py, type_obj, owner) {
             Some(ft) => ft,
-            None => continue, // Skip unsupported types or handle as needed
+            None => FieldType::Unsupported(type_name.clone()),
         };
 
-        // Extract value
-        let py_value = dataclass_instance.getattr(py, &field_name[..])?;
-
-        // Convert PyObject to Rust-native FieldValue
-        let value = match field_type {
-            FieldType::Str => {
-                FieldValue::Str(py_value.extract::<String>(py)?)
-            },
-            FieldType::Int => {
-                FieldValue::Int(py_value.extract::<i64>(py)?)
-            },
-            FieldType::Float => {
-                FieldValue::Float(py_value.extract::<f64>(py)?)
-            },
-            FieldType::Bool => {
-                FieldValue::Bool(py_value.extract::<bool>(py)?)
-            },
-            FieldType::DateTime => {
-                let s: String = py_value.extract::<String>(py)?;
-                FieldValue::DateTime(s)
-            },
-            FieldType::Date => {
-                let s: String = py_value.extract::<String>(py)?;
-                FieldValue::Date(s)
-            },
-            FieldType::Time => {
-                let s: String = py_value.extract::<String>(py)?;
-                FieldValue::Time(s)
-            },
-            // Handle other types as needed
-        };
+        // Extract value, recursing to match the field's shape.
+        let value = extract_value(py, &field_type, py_value.as_ref(py))?;
 
         result.push(RustFieldInfo {
             field_name,
             field_type,
             type_name,
             value,
+            raw_value: py_value,
+            custom_coercion: None,
         });
     }
 
@@ -490,7 +2176,7 @@ fn get_field_info(py: Python<'_>, dataclass_instance: &PyObject, fields_dict: &P
 /// 2) Parse the field's value (e.g. str -> UUID, str -> date, etc.)
 /// 3) Validate the resulting value against the annotated type
 #[pyfunction]
-fn parse_datamodel(py: Python<'_>, dataclass_instance: PyObject) -> PyResult<Vec<(String, bool)>> {
+fn parse_datamodel(py: Python<'_>, dataclass_instance: PyObject) -> PyResult<Vec<ValidationError>> {
     // Acquire the GIL using `Python::with_gil`
     Python::with_gil(|py| {
         // 1) Get dataclass instance's class
@@ -504,29 +2190,110 @@ fn parse_datamodel(py: Python<'_>, dataclass_instance: PyObject) -> PyResult<Vec
         // 3) Convert Python fields into a native Rust Vec<RustFieldInfo>
         let field_infos = get_field_info(py, &dataclass_instance, fields_dict)?;
 
-        // 4) Drop the GIL before parallel processing
-        // Note: `Python::with_gil` automatically drops the GIL when the closure ends
-        // Hence, no need to explicitly drop `py` here
+        // 4) Fields with a custom coercion callable need the GIL, so they
+        // run sequentially; everything else is pure Rust and can fan out
+        // across `rayon`'s thread pool.
+        let (custom_fields, rust_fields): (Vec<_>, Vec<_>) = field_infos
+            .into_iter()
+            .partition(|field_info| field_info.custom_coercion.is_some());
+
+        // Accumulate a `ValidationError` per failing field instead of
+        // aborting on the first one; an empty list means everything parsed
+        // and validated cleanly.
+        let mut errors: Vec<ValidationError> = custom_fields
+            .into_iter()
+            .filter_map(|field_info| {
+                let callable = field_info.custom_coercion.as_ref().unwrap();
+                match callable.call1(py, (field_info.raw_value.clone_ref(py),)) {
+                    Ok(_) => None,
+                    Err(_) => Some(ValidationError {
+                        field: field_info.field_name.clone(),
+                        path: field_info.field_name,
+                        expected: field_info.type_name,
+                        got: field_info
+                            .raw_value
+                            .as_ref(py)
+                            .get_type()
+                            .name()
+                            .map(|n| n.to_string())
+                            .unwrap_or_default(),
+                    }),
+                }
+            })
+            .collect();
 
-        // 5) Perform parallel iteration over `field_infos`
-        let results: Vec<(String, bool)> = field_infos
+        // 5) Perform parallel iteration over the remaining, pure-Rust fields
+        let rust_errors: Vec<ValidationError> = rust_fields
             .into_par_iter()
-            .map(|field_info| {
+            .filter_map(|field_info| {
                 // Perform parsing and validation purely in Rust
                 let is_parsed = field_info.field_type.parse(&field_info.value);
-                if !is_parsed {
-                    return (field_info.field_name, false);
+                let is_valid = is_parsed && field_info.field_type.validate(&field_info.value);
+                if is_valid {
+                    None
+                } else {
+                    Some(ValidationError {
+                        field: field_info.field_name.clone(),
+                        path: field_info.field_name,
+                        expected: field_info.field_type.describe(),
+                        got: field_info.value.describe().to_string(),
+                    })
                 }
-
-                let is_valid = field_info.field_type.validate(&field_info.value);
-                (field_info.field_name, is_valid)
             })
             .collect();
 
-        Ok(results)
+        errors.extend(rust_errors);
+        Ok(errors)
     })
 }
 
+/// Like `parse_datamodel`, but returns the actual coerced native values
+/// (datetime/date/time objects, etc.) instead of a pass/fail boolean mask,
+/// and optionally writes them back onto the instance via `setattr`.
+///
+/// # Returns
+/// A `(coerced, failures)` tuple: a `{field_name: value}` dict of the
+/// fields that converted successfully, and a list of field names that did not.
+#[pyfunction]
+#[pyo3(signature = (dataclass_instance, set_attrs=false))]
+fn coerce_datamodel(py: Python<'_>, dataclass_instance: PyObject, set_attrs: bool) -> PyResult<(Py<PyDict>, Vec<String>)> {
+    let dataclass_type: &PyType = dataclass_instance.as_ref(py).get_type();
+    let fields_dict: &PyDict = dataclass_type
+        .getattr("__dataclass_fields__")?
+        .downcast::<PyDict>()?;
+    let field_infos = get_field_info(py, &dataclass_instance, fields_dict)?;
+
+    let coerced = PyDict::new(py);
+    let mut failures = Vec::new();
+
+    for field_info in field_infos {
+        if let Some(callable) = &field_info.custom_coercion {
+            match callable.call1(py, (field_info.raw_value.clone_ref(py),)) {
+                Ok(value) => {
+                    if set_attrs {
+                        dataclass_instance.setattr(py, field_info.field_name.as_str(), value.clone_ref(py))?;
+                    }
+                    coerced.set_item(&field_info.field_name, value)?;
+                }
+                Err(_) => failures.push(field_info.field_name),
+            }
+            continue;
+        }
+
+        match field_info.field_type.coerce(py, &field_info.value)? {
+            Some(value) => {
+                if set_attrs {
+                    dataclass_instance.setattr(py, field_info.field_name.as_str(), value.clone_ref(py))?;
+                }
+                coerced.set_item(&field_info.field_name, value)?;
+            }
+            None => failures.push(field_info.field_name),
+        }
+    }
+
+    Ok((coerced.into(), failures))
+}
+
 
 /// Python module declaration
 #[pymodule]
@@ -535,8 +2302,441 @@ fn rst_converters(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(to_boolean, m)?)?;
     m.add_function(wrap_pyfunction!(to_date, m)?)?;
     m.add_function(wrap_pyfunction!(to_datetime, m)?)?;
+    m.add_function(wrap_pyfunction!(to_time, m)?)?;
+    m.add_function(wrap_pyfunction!(to_timedelta, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_temporal, m)?)?;
+    m.add_function(wrap_pyfunction!(precise_diff, m)?)?;
     m.add_function(wrap_pyfunction!(to_timestamp, m)?)?;
+    m.add_function(wrap_pyfunction!(to_list_parallel, m)?)?;
     m.add_function(wrap_pyfunction!(validate_datamodel, m)?)?;
     m.add_function(wrap_pyfunction!(parse_datamodel, m)?)?;
+    m.add_function(wrap_pyfunction!(coerce_datamodel, m)?)?;
+    m.add_class::<ValidationError>()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precise_diff_components_no_borrowing() {
+        let start = (2020, 1, 1, 0, 0, 0, 0);
+        let end = (2022, 3, 4, 5, 6, 7, 8);
+        assert_eq!(precise_diff_components(start, end), (2, 2, 3, 5, 6, 7, 8));
+    }
+
+    #[test]
+    fn precise_diff_components_borrows_day_across_shorter_month() {
+        // Jan 31 -> Mar 1: Feb's 28 days (non-leap year) aren't enough to
+        // settle the day borrow on their own, so it cascades back into
+        // January too, collapsing to a pure day count (29 days) rather
+        // than ever going negative.
+        let start = (2023, 1, 31, 0, 0, 0, 0);
+        let end = (2023, 3, 1, 0, 0, 0, 0);
+        assert_eq!(precise_diff_components(start, end), (0, 0, 29, 0, 0, 0, 0));
+    }
+
+    #[test]
+    fn precise_diff_components_borrows_day_across_leap_february() {
+        // Same cascade as above, but Feb 2024 has 29 days (leap year), for
+        // a raw distance of 30 days.
+        let start = (2024, 1, 31, 0, 0, 0, 0);
+        let end = (2024, 3, 1, 0, 0, 0, 0);
+        assert_eq!(precise_diff_components(start, end), (0, 0, 30, 0, 0, 0, 0));
+    }
+
+    #[test]
+    fn precise_diff_components_cascades_through_every_unit() {
+        // 0 of everything minus 1 microsecond must borrow all the way up
+        // through seconds, minutes, hours, days and months.
+        let start = (2023, 12, 31, 23, 59, 59, 999_999);
+        let end = (2024, 1, 1, 0, 0, 0, 0);
+        assert_eq!(precise_diff_components(start, end), (0, 0, 0, 0, 0, 0, 1));
+    }
+
+    #[test]
+    fn to_date_strict_accepts_leap_day() {
+        Python::with_gil(|py| {
+            let date = to_date(py, "2024-02-29", None, None, false, false, true).unwrap();
+            let date = date.as_ref(py);
+            assert_eq!(date.get_year(), 2024);
+            assert_eq!(date.get_month(), 2);
+            assert_eq!(date.get_day(), 29);
+        });
+    }
+
+    #[test]
+    fn to_date_strict_rejects_leap_day_in_non_leap_year() {
+        Python::with_gil(|py| {
+            assert!(to_date(py, "2023-02-29", None, None, false, false, true).is_err());
+        });
+    }
+
+    #[test]
+    fn to_date_strict_rejects_non_iso_form() {
+        Python::with_gil(|py| {
+            // Strict mode has no fallback to the ambiguous %m/%d/%Y list.
+            assert!(to_date(py, "02/29/2024", None, None, false, false, true).is_err());
+        });
+    }
+
+    #[test]
+    fn to_datetime_strict_rejects_non_iso_form() {
+        Python::with_gil(|py| {
+            assert!(to_datetime(py, "02/29/2024 12:00:00", None, false, None, None, true).is_err());
+        });
+    }
+
+    #[test]
+    fn parse_iso_duration_accepts_negative_components() {
+        Python::with_gil(|py| {
+            let delta = parse_iso_duration(py, "-1DT1H").unwrap();
+            let delta = delta.as_ref(py);
+            // -1 day + 1 hour is already in timedelta's normalized form
+            // (0 <= seconds < 86400), same as Python's timedelta(days=-1,
+            // hours=1).
+            assert_eq!(delta.get_days(), -1);
+            assert_eq!(delta.get_seconds(), 3600);
+        });
+    }
+
+    #[test]
+    fn to_list_parallel_matches_serial_fallback() {
+        Python::with_gil(|py| {
+            let input = PyList::new(py, ["1", "2", "3", "4", "5"]);
+            // min_chunk=1 forces every item into its own rayon chunk, which
+            // should still produce the same ordered output as the serial
+            // (non-chunked) path.
+            let parallel = to_list_parallel(py, "int", input, 1).unwrap();
+            let serial = to_list_serial(py, "int", input).unwrap();
+            assert_eq!(
+                parallel.extract::<Vec<i64>>(py).unwrap(),
+                serial.extract::<Vec<i64>>(py).unwrap(),
+            );
+            assert_eq!(parallel.extract::<Vec<i64>>(py).unwrap(), vec![1, 2, 3, 4, 5]);
+        });
+    }
+
+    #[test]
+    fn to_list_parallel_falls_back_to_serial_for_non_scalar_items() {
+        Python::with_gil(|py| {
+            // An arbitrary object (here, a list) isn't a ScalarInput, so
+            // the whole call must fall back to to_list_serial instead of
+            // erroring out of the rayon path.
+            let item: PyObject = PyList::empty(py).into_py(py);
+            let input = PyList::new(py, [item]);
+            assert!(to_list_parallel(py, "str", input, 1024).is_ok());
+        });
+    }
+
+    #[test]
+    fn disambiguate_numeric_date_respects_date_order() {
+        // "03/04/2023" is ambiguous between March 4 and April 3.
+        assert_eq!(
+            disambiguate_numeric_date("03/04/2023", DateOrder::Mdy),
+            Some((2023, 3, 4))
+        );
+        assert_eq!(
+            disambiguate_numeric_date("03/04/2023", DateOrder::Dmy),
+            Some((2023, 4, 3))
+        );
+    }
+
+    #[test]
+    fn disambiguate_numeric_date_detects_leading_four_digit_year() {
+        assert_eq!(
+            disambiguate_numeric_date("2023-04-03", DateOrder::Mdy),
+            Some((2023, 4, 3))
+        );
+    }
+
+    #[test]
+    fn disambiguate_numeric_date_rejects_non_triple() {
+        assert_eq!(disambiguate_numeric_date("2023-04", DateOrder::Ymd), None);
+        assert_eq!(disambiguate_numeric_date("not a date", DateOrder::Ymd), None);
+    }
+
+    #[test]
+    fn fuzzy_extract_date_reads_month_name_and_ordinal_day() {
+        Python::with_gil(|py| {
+            assert_eq!(
+                fuzzy_extract_date(py, "I first released this on the 17th of June, 2011", false),
+                Some((2011, 6, 17))
+            );
+        });
+    }
+
+    #[test]
+    fn fuzzy_extract_date_dayfirst_resolves_ambiguous_numbers() {
+        Python::with_gil(|py| {
+            assert_eq!(fuzzy_extract_date(py, "03 04 2011", true), Some((2011, 4, 3)));
+            assert_eq!(fuzzy_extract_date(py, "03 04 2011", false), Some((2011, 3, 4)));
+        });
+    }
+
+    #[test]
+    fn fuzzy_extract_date_returns_none_without_any_date_token() {
+        Python::with_gil(|py| {
+            assert_eq!(fuzzy_extract_date(py, "no date here at all", false), None);
+        });
+    }
+
+    /// Runs `code` (which must bind `instance` to the dataclass instance
+    /// under test) inside a throwaway module registered under
+    /// `module_name`, so a class defined by `code` gets a `__module__` that
+    /// actually resolves through `sys.modules` — required for
+    /// `classify_type`'s ForwardRef/string-annotation resolution to find
+    /// the right globals. Returns the bound `instance`.
+    fn build_instance(py: Python<'_>, module_name: &str, code: &str) -> PyObject {
+        let sys_modules: &PyDict = py.import("sys").unwrap().getattr("modules").unwrap().downcast().unwrap();
+        let module = py.import("types").unwrap().call_method1("ModuleType", (module_name,)).unwrap();
+        sys_modules.set_item(module_name, module).unwrap();
+        let module_dict: &PyDict = module.getattr("__dict__").unwrap().downcast().unwrap();
+        py.run(code, Some(module_dict), None).unwrap();
+        module_dict.get_item("instance").unwrap().unwrap().into_py(py)
+    }
+
+    #[test]
+    fn parse_datamodel_accepts_naive_datetime_field() {
+        // Regression test: extract_value stringifies a naive
+        // datetime.datetime via str() as "YYYY-MM-DD HH:MM:SS" (no offset),
+        // which FieldType::parse must accept just like to_datetime does.
+        Python::with_gil(|py| {
+            let instance = build_instance(
+                py,
+                "test_mod_naive_datetime",
+                "import dataclasses, datetime\n\
+                 @dataclasses.dataclass\n\
+                 class Event:\n\
+                 \x20   happened_at: datetime.datetime\n\
+                 instance = Event(happened_at=datetime.datetime(2023, 1, 1, 12, 0, 0))\n",
+            );
+            let errors = parse_datamodel(py, instance).unwrap();
+            assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+        });
+    }
+
+    #[test]
+    fn parse_datamodel_and_validate_datamodel_agree_on_recursive_containers() {
+        // chunk1-1: list/dict/tuple recursion should be accepted by both
+        // pipelines for valid data and rejected by both for invalid data.
+        Python::with_gil(|py| {
+            let valid = build_instance(
+                py,
+                "test_mod_containers_valid",
+                "import dataclasses\n\
+                 @dataclasses.dataclass\n\
+                 class Bag:\n\
+                 \x20   tags: list[str]\n\
+                 \x20   counts: dict[str, int]\n\
+                 \x20   point: tuple[int, int]\n\
+                 instance = Bag(tags=['a', 'b'], counts={'x': 1}, point=(1, 2))\n",
+            );
+            assert!(validate_datamodel(py, valid.clone_ref(py)).unwrap().is_empty());
+            assert!(parse_datamodel(py, valid).unwrap().is_empty());
+
+            let invalid = build_instance(
+                py,
+                "test_mod_containers_invalid",
+                "import dataclasses\n\
+                 @dataclasses.dataclass\n\
+                 class Bag:\n\
+                 \x20   tags: list[str]\n\
+                 \x20   counts: dict[str, int]\n\
+                 \x20   point: tuple[int, int]\n\
+                 instance = Bag(tags=['a', 2], counts={'x': 1}, point=(1, 2))\n",
+            );
+            assert!(!validate_datamodel(py, invalid.clone_ref(py)).unwrap().is_empty());
+            assert!(!parse_datamodel(py, invalid).unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn discriminated_union_matches_tagged_variant() {
+        // chunk1-2: a discriminated Union should jump straight to the
+        // matching variant and validate/parse it successfully.
+        Python::with_gil(|py| {
+            let instance = build_instance(
+                py,
+                "test_mod_discriminated_match",
+                "import dataclasses, typing\n\
+                 @dataclasses.dataclass\n\
+                 class Cat:\n\
+                 \x20   __discriminator__ = 'species'\n\
+                 \x20   species: typing.Literal['cat'] = 'cat'\n\
+                 \x20   lives: int = 9\n\
+                 @dataclasses.dataclass\n\
+                 class Dog:\n\
+                 \x20   __discriminator__ = 'species'\n\
+                 \x20   species: typing.Literal['dog'] = 'dog'\n\
+                 \x20   breed: str = 'mutt'\n\
+                 @dataclasses.dataclass\n\
+                 class Owner:\n\
+                 \x20   pet: typing.Union[Cat, Dog]\n\
+                 instance = Owner(pet=Dog(breed='lab'))\n",
+            );
+            assert!(validate_datamodel(py, instance.clone_ref(py)).unwrap().is_empty());
+            assert!(parse_datamodel(py, instance).unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn discriminated_union_unmatched_tag_reports_error_without_raising() {
+        // Regression test: an unmatched discriminator must come back as a
+        // ValidationError from both pipelines, not a raised exception.
+        Python::with_gil(|py| {
+            let instance = build_instance(
+                py,
+                "test_mod_discriminated_unmatched",
+                "import dataclasses, typing\n\
+                 @dataclasses.dataclass\n\
+                 class Cat:\n\
+                 \x20   __discriminator__ = 'species'\n\
+                 \x20   species: typing.Literal['cat'] = 'cat'\n\
+                 @dataclasses.dataclass\n\
+                 class Dog:\n\
+                 \x20   __discriminator__ = 'species'\n\
+                 \x20   species: typing.Literal['dog'] = 'dog'\n\
+                 @dataclasses.dataclass\n\
+                 class Bird:\n\
+                 \x20   species: str = 'bird'\n\
+                 @dataclasses.dataclass\n\
+                 class Owner:\n\
+                 \x20   pet: typing.Union[Cat, Dog]\n\
+                 instance = Owner.__new__(Owner)\n\
+                 instance.pet = Bird()\n",
+            );
+            let validate_errors = validate_datamodel(py, instance.clone_ref(py)).unwrap();
+            assert!(!validate_errors.is_empty());
+            let parse_errors = parse_datamodel(py, instance).unwrap();
+            assert!(!parse_errors.is_empty());
+        });
+    }
+
+    #[test]
+    fn coerce_datamodel_reports_unmatched_discriminator_as_failure() {
+        // Regression test: FieldType::coerce used to ignore the tag
+        // mismatch and return an empty dict as a "successful" coercion,
+        // which with set_attrs=true would silently overwrite the field
+        // with {} instead of reporting it in failures.
+        Python::with_gil(|py| {
+            let instance = build_instance(
+                py,
+                "test_mod_coerce_discriminated_unmatched",
+                "import dataclasses, typing\n\
+                 @dataclasses.dataclass\n\
+                 class Cat:\n\
+                 \x20   __discriminator__ = 'species'\n\
+                 \x20   species: typing.Literal['cat'] = 'cat'\n\
+                 @dataclasses.dataclass\n\
+                 class Dog:\n\
+                 \x20   __discriminator__ = 'species'\n\
+                 \x20   species: typing.Literal['dog'] = 'dog'\n\
+                 @dataclasses.dataclass\n\
+                 class Bird:\n\
+                 \x20   species: str = 'bird'\n\
+                 @dataclasses.dataclass\n\
+                 class Owner:\n\
+                 \x20   pet: typing.Union[Cat, Dog]\n\
+                 instance = Owner.__new__(Owner)\n\
+                 instance.pet = Bird()\n",
+            );
+            let (coerced, failures) = coerce_datamodel(py, instance, false).unwrap();
+            assert!(failures.iter().any(|name| name == "pet"));
+            assert!(coerced.as_ref(py).get_item("pet").unwrap().is_none());
+        });
+    }
+
+    #[test]
+    fn parse_datamodel_reports_unsupported_annotation_like_validate_datamodel() {
+        // Regression test: get_field_info used to silently drop a field
+        // whose annotation classify_type couldn't resolve, so
+        // parse_datamodel came back clean where validate_datamodel flagged
+        // the field as unsupported.
+        Python::with_gil(|py| {
+            let instance = build_instance(
+                py,
+                "test_mod_unsupported_annotation",
+                "import dataclasses, threading\n\
+                 @dataclasses.dataclass\n\
+                 class Weird:\n\
+                 \x20   lock: threading.Lock = dataclasses.field(default_factory=threading.Lock)\n\
+                 instance = Weird()\n",
+            );
+            let validate_errors = validate_datamodel(py, instance.clone_ref(py)).unwrap();
+            assert!(!validate_errors.is_empty());
+            let parse_errors = parse_datamodel(py, instance).unwrap();
+            assert_eq!(parse_errors.len(), validate_errors.len());
+            assert!(parse_errors[0].expected.contains("unsupported"));
+        });
+    }
+
+    #[test]
+    fn custom_coercion_callable_is_used_instead_of_default_classification() {
+        // chunk1-3: a field with a registered decoder should have its
+        // callable invoked rather than going through scalar classification.
+        Python::with_gil(|py| {
+            let instance = build_instance(
+                py,
+                "test_mod_custom_coercion",
+                "import dataclasses\n\
+                 def upper(value):\n\
+                 \x20   return value.upper()\n\
+                 @dataclasses.dataclass\n\
+                 class Tagged:\n\
+                 \x20   name: str = dataclasses.field(\n\
+                 \x20       default='low', metadata={'decoder': upper}\n\
+                 \x20   )\n\
+                 instance = Tagged()\n",
+            );
+            let (coerced, _) = coerce_datamodel(py, instance.clone_ref(py), false).unwrap();
+            let name = coerced.as_ref(py).get_item("name").unwrap().unwrap();
+            assert_eq!(name.extract::<String>().unwrap(), "LOW");
+            assert!(parse_datamodel(py, instance).unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn parse_datamodel_accepts_uuid_decimal_and_bytes_fields() {
+        // chunk1-6: UUID/Decimal/bytes round-trip through parse_datamodel.
+        Python::with_gil(|py| {
+            let instance = build_instance(
+                py,
+                "test_mod_uuid_decimal_bytes",
+                "import dataclasses, uuid, decimal\n\
+                 @dataclasses.dataclass\n\
+                 class Record:\n\
+                 \x20   id: uuid.UUID\n\
+                 \x20   amount: decimal.Decimal\n\
+                 \x20   payload: bytes\n\
+                 instance = Record(\n\
+                 \x20   id=uuid.uuid4(), amount=decimal.Decimal('1.50'), payload=b'abc'\n\
+                 )\n",
+            );
+            assert!(parse_datamodel(py, instance).unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn classify_type_resolves_forward_ref_string_annotations() {
+        // chunk1-5: a string/ForwardRef annotation for a nested dataclass
+        // must resolve the same as the real type would.
+        Python::with_gil(|py| {
+            let instance = build_instance(
+                py,
+                "test_mod_forward_ref",
+                "import dataclasses\n\
+                 @dataclasses.dataclass\n\
+                 class Address:\n\
+                 \x20   zipcode: str\n\
+                 @dataclasses.dataclass\n\
+                 class Person:\n\
+                 \x20   address: 'Address'\n\
+                 instance = Person(address=Address(zipcode='12345'))\n",
+            );
+            assert!(validate_datamodel(py, instance.clone_ref(py)).unwrap().is_empty());
+            assert!(parse_datamodel(py, instance).unwrap().is_empty());
+        });
+    }
+}